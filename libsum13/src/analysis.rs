@@ -0,0 +1,160 @@
+//! Here are all the functions that analyse how the digits sums are spread over the integers
+
+mod collisions;
+mod gaps;
+mod moments;
+
+pub use collisions::{colliding_pairs, colliding_pairs_within};
+pub use gaps::{Gap, GapStatistics, Step, gaps};
+pub use moments::{Moments, digits_sums_squares_total, digits_sums_total};
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, num::NonZeroU8};
+
+    use crate::DigitSum;
+
+    use super::{
+        GapStatistics, Moments, Step, colliding_pairs, colliding_pairs_within,
+        digits_sums_squares_total, digits_sums_total, gaps,
+    };
+
+    fn brute_force(end: u64, window: u64) -> u128 {
+        let sums = (0..end).map(|value| value.digits_sum()).collect::<Vec<_>>();
+        let mut pairs = 0;
+
+        for b in 0..sums.len() {
+            for a in 0..b {
+                if sums[a] == sums[b] && ((b - a) as u64) < window {
+                    pairs += 1;
+                }
+            }
+        }
+
+        pairs
+    }
+
+    #[test]
+    fn test_colliding_pairs() {
+        for end in (0..=300).chain([999, 1000, 1001, 2024]) {
+            assert_eq!(colliding_pairs(end), brute_force(end, u64::MAX));
+
+            for window in [0, 1, 2, 9, 10, 11, 100, 500, u64::MAX] {
+                assert_eq!(
+                    colliding_pairs_within(end, window),
+                    brute_force(end, window),
+                    "end {end}, window {window}"
+                );
+            }
+        }
+
+        assert_eq!(
+            colliding_pairs_within(123_456, u64::MAX),
+            colliding_pairs(123_456)
+        );
+    }
+
+    #[test]
+    fn test_moments() {
+        for end in (0..=2000).chain([12_345, 100_000, 987_654]) {
+            let sums = (0..end).map(|value| value.digits_sum() as u128);
+
+            assert_eq!(digits_sums_total(end), sums.clone().sum::<u128>());
+            assert_eq!(
+                digits_sums_squares_total(end),
+                sums.map(|sum| sum * sum).sum::<u128>()
+            );
+        }
+
+        // Every digit is uniform over `0..10^d`: 4.5 on average with the variance 8.25
+        for digits in 1..=19 {
+            let end = 10u64.pow(digits);
+            let moments = Moments::new(end);
+
+            assert_eq!(
+                moments.sum,
+                45 * digits as u128 * 10u128.pow(digits - 1)
+            );
+            assert!((moments.mean() - 4.5 * digits as f64).abs() < 1e-9);
+            assert!(
+                (moments.variance() / (8.25 * digits as f64) - 1.0).abs()
+                    < 1e-15
+            );
+        }
+
+        for end in 1..=2000 {
+            let moments = Moments::new(end);
+            let numerator = moments.count as u128 * moments.sum_of_squares
+                - moments.sum * moments.sum;
+
+            assert_eq!(
+                moments.variance(),
+                numerator as f64 / (end as f64 * end as f64),
+                "end {end}"
+            );
+        }
+
+        let moments = Moments::new(u64::MAX);
+        assert!(moments.sum > u64::MAX as u128);
+        assert_eq!(Moments::new(0).mean(), 0.0);
+        assert_eq!(Moments::new(1).variance(), 0.0);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let sum = NonZeroU8::new(13).unwrap();
+        assert_eq!(gaps(sum, 0).count(), 0);
+        assert_eq!(gaps(sum, 1).count(), 0);
+        assert_eq!(GapStatistics::new(sum, 0), GapStatistics::default());
+
+        for sum in (1..=40).filter_map(NonZeroU8::new) {
+            let terms = (0..200_000u64)
+                .filter(|value| value.digits_sum() == sum.get() as u64)
+                .take(2000)
+                .collect::<Vec<_>>();
+
+            let streamed = gaps(sum, terms.len() as u32).collect::<Vec<_>>();
+
+            assert_eq!(streamed.len(), terms.len() - 1);
+
+            for (gap, pair) in streamed.iter().zip(terms.windows(2)) {
+                assert_eq!(gap.from, pair[0]);
+                assert_eq!(gap.from + 9 * gap.gap, pair[1]);
+                assert_eq!(gap.step == Step::Fast, pair[1] - pair[0] == 9);
+            }
+
+            let statistics = GapStatistics::new(sum, terms.len() as u32);
+            let mut histogram = BTreeMap::new();
+            terms.windows(2).for_each(|pair| {
+                *histogram.entry((pair[1] - pair[0]) / 9).or_default() += 1
+            });
+
+            assert_eq!(statistics.histogram, histogram);
+            assert_eq!(
+                statistics.fast_steps + statistics.skip_steps,
+                terms.len() as u64 - 1
+            );
+            assert_eq!(
+                statistics.fast_steps,
+                histogram.get(&1).copied().unwrap_or_default()
+            );
+
+            let max = statistics.max_gap.unwrap();
+            let first_max = terms
+                .windows(2)
+                .find(|pair| (pair[1] - pair[0]) / 9 == max.gap)
+                .unwrap();
+
+            assert_eq!(max.gap, *histogram.keys().last().unwrap());
+            assert_eq!(max.from, first_max[0]);
+        }
+
+        let statistics = GapStatistics::new(NonZeroU8::new(1).unwrap(), 1);
+        assert_eq!(statistics.max_gap, None);
+        assert_eq!(statistics.fast_share(), 0.0);
+        assert!(
+            GapStatistics::new(NonZeroU8::new(13).unwrap(), 1000).fast_share()
+                > 0.5
+        );
+    }
+}
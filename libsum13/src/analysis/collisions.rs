@@ -0,0 +1,42 @@
+use crate::{
+    combinatorics::{MAX_SUM, histogram},
+    integer::DigitSumCounter,
+};
+
+/// The number of unordered pairs `a < b` in range `0..end` with the same digits sum.
+///
+/// Every digits sum `m` that `h` values share gives `h * (h - 1) / 2` pairs.
+pub fn colliding_pairs(end: u64) -> u128 {
+    histogram(end)
+        .into_iter()
+        .map(|count| count as u128 * count.saturating_sub(1) as u128 / 2)
+        .sum()
+}
+
+/// The same as `colliding_pairs`, but only the pairs with `b - a < window` are counted.
+///
+/// The window slides over the range keeping how many of its values have every digits sum,
+/// so every value pairs up with all the ones before it at once.
+pub fn colliding_pairs_within(end: u64, window: u64) -> u128 {
+    if window == 0 {
+        return 0;
+    }
+
+    let mut counts = [0u64; MAX_SUM + 1];
+    let mut leaving = DigitSumCounter::new(0..);
+    let mut pairs = 0;
+
+    for (elem, digits_sum) in DigitSumCounter::new(0..end) {
+        if elem >= window
+            && let Some((_, left)) = leaving.next()
+        {
+            counts[left as usize] -= 1;
+        }
+
+        pairs += counts[digits_sum as usize] as u128;
+
+        counts[digits_sum as usize] += 1;
+    }
+
+    pairs
+}
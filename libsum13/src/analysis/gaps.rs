@@ -0,0 +1,89 @@
+use std::{collections::BTreeMap, num::NonZeroU8};
+
+use crate::{integer::WithDigitSum, traits::SumSequencer};
+
+/// Which way `WithDigitSum` got from one term to the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// The term plus 9 has the same digits sum
+    Fast,
+    /// The next hundred with a small enough digits sum is found first
+    HundredSkip,
+}
+
+/// Two consecutive terms: `from` and `from + 9 * gap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Gap {
+    pub from: u64,
+    pub gap: u64,
+    pub step: Step,
+}
+
+/// The gaps between the first `iterations` numbers which digits sum up to `sum`, in units of 9.
+///
+/// Numbers with the same digits sum are equal modulo 9, so the gaps are always whole.
+/// The fast path only ever makes a gap of 1, and nothing else does:
+/// if the term plus 9 has the digits sum, it's always taken.
+pub fn gaps(sum: NonZeroU8, iterations: u32) -> impl Iterator<Item = Gap> {
+    // `get_ints` yields the first term even for zero iterations, so the terms are limited here
+    let mut terms = WithDigitSum(sum)
+        .get_ints(u32::MAX)
+        .take(iterations as usize);
+    let mut previous = terms.next();
+
+    std::iter::from_fn(move || {
+        let from = previous?;
+        let to = terms.next()?;
+        previous = Some(to);
+
+        let gap = (to - from) / 9;
+        let step = if gap == 1 {
+            Step::Fast
+        } else {
+            Step::HundredSkip
+        };
+
+        Some(Gap { from, gap, step })
+    })
+}
+
+/// Everything `gaps` streams, folded into the histogram and the counts of the paths.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GapStatistics {
+    /// How many times every gap occurs
+    pub histogram: BTreeMap<u64, u64>,
+    /// The first of the greatest gaps, or `None` if there are no gaps at all
+    pub max_gap: Option<Gap>,
+    pub fast_steps: u64,
+    pub skip_steps: u64,
+}
+
+impl GapStatistics {
+    pub fn new(sum: NonZeroU8, iterations: u32) -> Self {
+        gaps(sum, iterations).fold(Self::default(), |mut statistics, gap| {
+            *statistics.histogram.entry(gap.gap).or_default() += 1;
+
+            if statistics.max_gap.is_none_or(|max| gap.gap > max.gap) {
+                statistics.max_gap = Some(gap);
+            }
+
+            match gap.step {
+                Step::Fast => statistics.fast_steps += 1,
+                Step::HundredSkip => statistics.skip_steps += 1,
+            }
+
+            statistics
+        })
+    }
+
+    /// The share of the steps taken by the fast path, zero if there are none.
+    pub fn fast_share(&self) -> f64 {
+        let steps = self.fast_steps + self.skip_steps;
+
+        if steps == 0 {
+            return 0.0;
+        }
+
+        self.fast_steps as f64 / steps as f64
+    }
+}
@@ -0,0 +1,89 @@
+use crate::combinatorics::histogram;
+
+/// Sums of the digits sums and of their squares over the range `0..count`.
+///
+/// They are exact, while the mean and the variance of the distribution are derived from them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Moments {
+    pub count: u64,
+    pub sum: u128,
+    pub sum_of_squares: u128,
+}
+
+impl Moments {
+    /// Every digits sum `m` is taken as many times as the histogram has it.
+    pub fn new(end: u64) -> Self {
+        let (sum, sum_of_squares) = histogram(end)
+            .into_iter()
+            .enumerate()
+            .fold((0, 0), |(sum, sum_of_squares), (digits_sum, count)| {
+                let digits_sum = digits_sum as u128;
+                let count = count as u128;
+
+                (
+                    sum + digits_sum * count,
+                    sum_of_squares + digits_sum * digits_sum * count,
+                )
+            });
+
+        Self {
+            count: end,
+            sum,
+            sum_of_squares,
+        }
+    }
+
+    /// Zero for the empty range.
+    pub fn mean(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        self.sum as f64 / self.count as f64
+    }
+
+    /// The population variance, zero for the empty range.
+    ///
+    /// `count * sum_of_squares - sum * sum` is exact, so there is no cancellation before the division.
+    pub fn variance(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let (high, low) = widening_mul(self.count as u128, self.sum_of_squares);
+        let (square_high, square_low) = widening_mul(self.sum, self.sum);
+        let (low, borrow) = low.overflowing_sub(square_low);
+        let high = high - square_high - borrow as u128;
+
+        let numerator = high as f64 * 2f64.powi(128) + low as f64;
+        let count = self.count as f64;
+
+        numerator / (count * count)
+    }
+}
+
+/// `a * b` split into the high and the low 128 bits, from the 64-bit halves of both.
+fn widening_mul(a: u128, b: u128) -> (u128, u128) {
+    let (a_high, a_low) = (a >> 64, a & u64::MAX as u128);
+    let (b_high, b_low) = (b >> 64, b & u64::MAX as u128);
+
+    let (middle, middle_carry) =
+        (a_low * b_high).overflowing_add(a_high * b_low);
+    let (low, low_carry) = (a_low * b_low).overflowing_add(middle << 64);
+    let high = a_high * b_high
+        + (middle >> 64)
+        + ((middle_carry as u128) << 64)
+        + low_carry as u128;
+
+    (high, low)
+}
+
+/// Σ digits_sum(n) for n in range `0..end`.
+pub fn digits_sums_total(end: u64) -> u128 {
+    Moments::new(end).sum
+}
+
+/// Σ digits_sum(n)² for n in range `0..end`.
+pub fn digits_sums_squares_total(end: u64) -> u128 {
+    Moments::new(end).sum_of_squares
+}
@@ -0,0 +1,298 @@
+//! Here are all the sequencers that restrict the digits sums in two bases at once
+
+use std::num::NonZeroU8;
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    DigitSum, impl_mut_for_refmut, integer,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+/// The second base, in which the digits sum is restricted too.
+///
+/// Both of them are powers of 2, so a number splits into the high and the low bits digit-wise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base {
+    /// The digits sum is the popcount
+    Binary,
+    Hexadecimal,
+}
+
+impl Base {
+    fn bits(self) -> u32 {
+        match self {
+            Self::Binary => 1,
+            Self::Hexadecimal => 4,
+        }
+    }
+
+    pub fn digits_sum(self, value: u64) -> u64 {
+        match self {
+            Self::Binary => value.count_ones() as u64,
+            Self::Hexadecimal => {
+                let mut sum = 0;
+                let mut value = value;
+
+                while value != 0 {
+                    sum += value & 0xF;
+                    value >>= 4;
+                }
+
+                sum
+            }
+        }
+    }
+}
+
+/// Numbers which decimal digits sum up to `sum` and which digits in `base` sum up to `base_sum`.
+pub struct WithDigitSumsInBases {
+    pub sum: NonZeroU8,
+    pub base: Base,
+    pub base_sum: u8,
+}
+impl_mut_for_refmut!(WithDigitSumsInBases);
+
+impl SumSequencer for WithDigitSumsInBases {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let base = self.base;
+        let base_sum = self.base_sum as u64;
+
+        integer::WithDigitSum(self.sum)
+            .get_ints(u32::MAX)
+            .filter(move |value| base.digits_sum(*value) == base_sum)
+            .take(iterations as usize)
+    }
+}
+
+/// No more than 10^6 low halves are tabulated.
+const MAX_LOW_DIGITS: u32 = 6;
+
+impl WithDigitSumsInBases {
+    /// Count all the numbers in range `0..end` with both of the digits sums.
+    ///
+    /// Meet in the middle: `n = high * 10^k + low`. The decimal sums of the halves just add up.
+    /// `10^k` is divisible by `2^k`, so the lowest bits of `n` are the lowest bits of `low`,
+    /// and only `low >> bits` carries into the high part in the second base.
+    /// The low halves are tabulated once by their decimal sum and that carry,
+    /// so every high half only walks through the carries instead of all the `10^k` low halves.
+    pub fn count(&self, end: u64) -> u64 {
+        let sum = self.sum.get() as u64;
+        let base_sum = self.base_sum as u64;
+        let base = self.base;
+
+        let low_digits = Self::low_digits(end, base);
+        let block = 10u64.pow(low_digits);
+        let halves = LowHalves::new(low_digits, base);
+
+        let count_high = move |high: u64, low_end: u64| {
+            let Some(left) = sum.checked_sub(high.digits_sum()) else {
+                return 0;
+            };
+
+            halves.count(high * block, left, base_sum, low_end)
+        };
+
+        #[cfg(feature = "parallel")]
+        let highs = (0..end / block).into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let highs = 0..end / block;
+
+        let full = highs.map(|high| count_high(high, block)).sum::<u64>();
+
+        full + count_high(end / block, end % block)
+    }
+
+    /// `k` that makes the table and the walks over the high halves take about the same time.
+    fn low_digits(end: u64, base: Base) -> u32 {
+        (0..=MAX_LOW_DIGITS)
+            .min_by_key(|digits| {
+                let block = 10u128.pow(*digits);
+                let carries = block >> split_bits(*digits, base);
+
+                block + (end as u128 / block) * carries
+            })
+            .expect("The range is not empty")
+    }
+}
+
+/// The bits below the split are the same for `low` and `high * 10^k + low`.
+/// The digits of the second base must not be split in the middle.
+fn split_bits(low_digits: u32, base: Base) -> u32 {
+    low_digits / base.bits() * base.bits()
+}
+
+/// Low halves in range `0..10^k` grouped by their decimal digits sum.
+///
+/// For each decimal sum there is a list of the carries `low >> bits`, in increasing order,
+/// and for every carry: how many of the low halves have each digits sum of the lowest bits.
+struct LowHalves {
+    base: Base,
+    bits: u32,
+    block: u64,
+    /// `carries[s]` are the carries of the low halves with decimal digits sum `s`
+    carries: Vec<Vec<u64>>,
+    /// `counts[s][i * stride + t]` is the number of those with carry `carries[s][i]`
+    /// and digits sum `t` of the lowest bits
+    counts: Vec<Vec<u32>>,
+    stride: usize,
+}
+
+impl LowHalves {
+    fn new(low_digits: u32, base: Base) -> Self {
+        let bits = split_bits(low_digits, base);
+        let block = 10u64.pow(low_digits);
+        let stride = base.digits_sum((1 << bits) - 1) as usize + 1;
+        let max_sum = 9 * low_digits as usize;
+
+        let mut carries = vec![Vec::new(); max_sum + 1];
+        let mut counts = vec![Vec::new(); max_sum + 1];
+
+        for low in 0..block {
+            let sum = low.digits_sum() as usize;
+            let carry = low >> bits;
+
+            // The lows go in order, so a new carry is always the greatest one
+            if carries[sum].last() != Some(&carry) {
+                carries[sum].push(carry);
+                let length = counts[sum].len();
+                counts[sum].resize(length + stride, 0);
+            }
+
+            let index = counts[sum].len() - stride
+                + base.digits_sum(low & ((1 << bits) - 1)) as usize;
+            counts[sum][index] += 1;
+        }
+
+        Self {
+            base,
+            bits,
+            block,
+            carries,
+            counts,
+            stride,
+        }
+    }
+
+    /// Count the numbers `start + low` for `low` in range `0..low_end`
+    /// with the decimal digits sum of `low` equal to `sum`, and the digits sum in the base equal to `base_sum`.
+    ///
+    /// `start` must be a multiple of the block.
+    fn count(&self, start: u64, sum: u64, base_sum: u64, low_end: u64) -> u64 {
+        let Some(carries) = self.carries.get(sum as usize) else {
+            return 0;
+        };
+        let counts = &self.counts[sum as usize];
+        let high = start >> self.bits;
+
+        // The carries below `low_end >> bits` have all of their lows in range
+        let covered =
+            carries.partition_point(|carry| *carry < low_end >> self.bits);
+
+        let full = carries[..covered]
+            .iter()
+            .enumerate()
+            .filter_map(|(i, carry)| {
+                let lowest =
+                    base_sum.checked_sub(self.base.digits_sum(high + carry))?;
+
+                (lowest < self.stride as u64)
+                    .then(|| counts[i * self.stride + lowest as usize] as u64)
+            })
+            .sum::<u64>();
+
+        // The only carry that is partially in range is checked one by one
+        let partial = if low_end < self.block {
+            (low_end >> self.bits << self.bits..low_end)
+                .filter(|low| low.digits_sum() == sum)
+                .filter(|low| self.base.digits_sum(start + low) == base_sum)
+                .count() as u64
+        } else {
+            0
+        };
+
+        full + partial
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU8;
+
+    use crate::{DigitSum, traits::SumSequencer};
+
+    use super::{Base, WithDigitSumsInBases};
+
+    #[test]
+    fn test_base_digits_sum() {
+        assert_eq!(Base::Binary.digits_sum(0b1011_0001), 4);
+        assert_eq!(Base::Hexadecimal.digits_sum(0xF0A3), 15 + 10 + 3);
+        assert_eq!(Base::Hexadecimal.digits_sum(u64::MAX), 15 * 16);
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let limit = 200_000u64;
+
+        for base in [Base::Binary, Base::Hexadecimal] {
+            for (sum, base_sum) in [
+                (1, 1),
+                (4, 2),
+                (13, 5),
+                (13, 7),
+                (13, 30),
+                (20, 9),
+                (40, 10),
+            ] {
+                let ints = WithDigitSumsInBases {
+                    sum: NonZeroU8::new(sum).unwrap(),
+                    base,
+                    base_sum,
+                };
+
+                let expected = (0..limit)
+                    .filter(|value| value.digits_sum() == sum as u64)
+                    .filter(|value| base.digits_sum(*value) == base_sum as u64)
+                    .collect::<Vec<_>>();
+
+                // Some of them (like 1 and 1) never have another number, so the search must be limited
+                assert_eq!(
+                    ints.get_ints(expected.len() as u32).collect::<Vec<_>>(),
+                    expected,
+                    "{base:?}, sum {sum}, base sum {base_sum}"
+                );
+
+                for end in [0, 1, 9, 100, 1023, 4096, 12345, 99_999, limit] {
+                    assert_eq!(
+                        ints.count(end),
+                        expected.iter().filter(|value| **value < end).count()
+                            as u64,
+                        "{base:?}, sum {sum}, base sum {base_sum}, end {end}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_against_sequence() {
+        let end = 500_000_007;
+
+        for base in [Base::Binary, Base::Hexadecimal] {
+            let ints = WithDigitSumsInBases {
+                sum: NonZeroU8::new(13).unwrap(),
+                base,
+                base_sum: 13,
+            };
+
+            assert_eq!(
+                ints.count(end),
+                ints.get_ints(u32::MAX)
+                    .take_while(|value| *value < end)
+                    .count() as u64,
+                "{base:?}"
+            );
+        }
+    }
+}
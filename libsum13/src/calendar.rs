@@ -0,0 +1,153 @@
+//! Here are all the sequencers of dates and times of day which digits sum up to the given number
+
+mod clock;
+mod date;
+mod fields;
+
+pub use clock::{Time, TimesWithDigitSum};
+pub use date::{Date, DatesWithDigitSum, days_in_month, is_leap_year};
+
+#[cfg(test)]
+mod tests {
+    use std::{num::NonZeroU8, ops::Bound};
+
+    use super::{
+        Date, DatesWithDigitSum, Time, TimesWithDigitSum, days_in_month,
+    };
+
+    fn all_dates(start: Date) -> impl Iterator<Item = Date> {
+        std::iter::successors(Some(start), |date| date.next())
+    }
+
+    fn all_times() -> impl Iterator<Item = Time> {
+        std::iter::successors(Some(Time::MIN), |time| time.next())
+    }
+
+    #[test]
+    fn test_calendar() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(1900, 2), 28);
+        assert_eq!(days_in_month(2000, 2), 29);
+        assert_eq!(Date::new(2023, 2, 29), None);
+        assert_eq!(Date::new(2023, 4, 31), None);
+        assert_eq!(Date::new(0, 1, 1), None);
+
+        assert_eq!(all_dates(Date::MIN).count(), 3_652_059);
+        assert_eq!(all_dates(Date::MIN).last(), Some(Date::MAX));
+        assert_eq!(all_times().count(), 86_400);
+        assert_eq!(all_times().last(), Some(Time::MAX));
+
+        assert_eq!(Date::new(2024, 3, 1).unwrap().to_string(), "2024-03-01");
+        assert_eq!(Date::new(2024, 3, 1).unwrap().digits_sum(), 12);
+        assert_eq!(Time::new(7, 5, 30).unwrap().to_string(), "07:05:30");
+    }
+
+    #[test]
+    fn test_dates_against_brute_force() {
+        let start = Date::new(1896, 11, 17).unwrap();
+        let end = Date::new(2105, 3, 2).unwrap();
+
+        for sum in (1..=50).filter_map(NonZeroU8::new) {
+            let expected = all_dates(start)
+                .take_while(|date| *date < end)
+                .filter(|date| date.digits_sum() == sum.get() as u64)
+                .collect::<Vec<_>>();
+
+            let dates = DatesWithDigitSum(sum);
+
+            assert_eq!(
+                dates.get_dates(start..end).collect::<Vec<_>>(),
+                expected,
+                "sum {sum}"
+            );
+            assert_eq!(dates.count(start..end), expected.len() as u64);
+
+            for pair in expected.windows(2) {
+                assert_eq!(dates.next_after(pair[0]), Some(pair[1]));
+            }
+        }
+
+        for sum in (1..=56).filter_map(NonZeroU8::new) {
+            let dates = DatesWithDigitSum(sum);
+
+            assert_eq!(
+                dates.count(..),
+                dates.get_dates(..).count() as u64,
+                "sum {sum}"
+            );
+        }
+
+        let dates = DatesWithDigitSum::new(13);
+
+        assert_eq!(
+            dates.next_after(Date::new(2024, 2, 29).unwrap()),
+            Date::new(2024, 3, 2)
+        );
+        assert_eq!(dates.next_after(Date::MAX), None);
+        assert_eq!(
+            DatesWithDigitSum::new(56).get_dates(..).last(),
+            Date::new(9999, 9, 29)
+        );
+
+        // 9999-12-31 has the digits sum of 43
+        let last = DatesWithDigitSum::new(43);
+        let december = Date::new(9999, 12, 20).unwrap();
+
+        assert_eq!(last.get_dates(..=Date::MAX).last(), Some(Date::MAX));
+        assert_eq!(last.get_dates(..Date::MAX).last(), Date::new(9999, 12, 22));
+        assert_eq!(
+            last.get_dates(december..).collect::<Vec<_>>(),
+            [(12, 22), (12, 31)]
+                .map(|(month, day)| Date::new(9999, month, day).unwrap())
+        );
+        assert_eq!(last.count(Date::MAX..=Date::MAX), 1);
+        assert_eq!(
+            last.count((Bound::Excluded(Date::MAX), Bound::Unbounded)),
+            0
+        );
+    }
+
+    #[test]
+    fn test_times_against_brute_force() {
+        for sum in (1..=45).filter_map(NonZeroU8::new) {
+            let expected = all_times()
+                .filter(|time| time.digits_sum() == sum.get() as u64)
+                .collect::<Vec<_>>();
+
+            let times = TimesWithDigitSum(sum);
+
+            assert_eq!(
+                times.get_times(..).collect::<Vec<_>>(),
+                expected,
+                "sum {sum}"
+            );
+            assert_eq!(
+                times.get_times(Time::MIN..Time::MAX).collect::<Vec<_>>(),
+                expected
+                    .iter()
+                    .copied()
+                    .filter(|time| *time < Time::MAX)
+                    .collect::<Vec<_>>(),
+                "sum {sum}"
+            );
+
+            let start = Time::new(13, 31, 7).unwrap();
+            assert_eq!(
+                times.count(start..=Time::MAX),
+                expected.iter().filter(|time| **time >= start).count() as u64
+            );
+            assert_eq!(
+                times.count((Bound::Excluded(start), Bound::Unbounded)),
+                expected.iter().filter(|time| **time > start).count() as u64
+            );
+
+            let mut current = times.next_after(Time::MIN);
+            for time in expected.iter().filter(|time| **time > Time::MIN) {
+                assert_eq!(current, Some(*time));
+                current = times.next_after(*time);
+            }
+            assert_eq!(current, None);
+        }
+    }
+}
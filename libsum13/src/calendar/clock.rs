@@ -0,0 +1,155 @@
+use std::{
+    fmt,
+    num::NonZeroU8,
+    ops::{RangeBounds, RangeInclusive},
+};
+
+use super::fields::{self, Fields};
+use crate::{DigitSum, new_expect};
+
+/// A time of day, from 00:00:00 to 23:59:59.
+///
+/// The fields go from the most significant, so the derived order is the chronological one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl Time {
+    pub const MIN: Time = Time {
+        hour: 0,
+        minute: 0,
+        second: 0,
+    };
+    pub const MAX: Time = Time {
+        hour: 23,
+        minute: 59,
+        second: 59,
+    };
+
+    /// `None` if there is no such time.
+    pub fn new(hour: u8, minute: u8, second: u8) -> Option<Self> {
+        (hour < 24 && minute < 60 && second < 60).then_some(Self {
+            hour,
+            minute,
+            second,
+        })
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// The following second, if it is still the same day.
+    pub fn next(self) -> Option<Self> {
+        if self.second < 59 {
+            Some(Self {
+                second: self.second + 1,
+                ..self
+            })
+        } else if self.minute < 59 {
+            Some(Self {
+                minute: self.minute + 1,
+                second: 0,
+                ..self
+            })
+        } else if self.hour < 23 {
+            Some(Self {
+                hour: self.hour + 1,
+                minute: 0,
+                second: 0,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn digits_sum(&self) -> u64 {
+        self.as_u64().digits_sum()
+    }
+
+    /// The time written as HHMMSS.
+    pub fn as_u64(&self) -> u64 {
+        self.hour as u64 * 10_000
+            + self.minute as u64 * 100
+            + self.second as u64
+    }
+}
+
+impl DigitSum for Time {
+    fn digits_sum(&self) -> u64 {
+        Time::digits_sum(self)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+impl Fields for Time {
+    const MIN: Self = Time::MIN;
+    const HIGH: RangeInclusive<u16> = 0..=23;
+    const MIDDLE: RangeInclusive<u8> = 0..=59;
+    /// A second has at most 5 + 9 = 14 as its digits sum
+    const MAX_LOW_SUM: u64 = 14;
+
+    fn low(_: u16, _: u8) -> RangeInclusive<u8> {
+        0..=59
+    }
+
+    fn fields(self) -> (u16, u8, u8) {
+        (self.hour as u16, self.minute, self.second)
+    }
+
+    fn from_fields(hour: u16, minute: u8, second: u8) -> Self {
+        Self {
+            hour: hour as u8,
+            minute,
+            second,
+        }
+    }
+
+    fn next(self) -> Option<Self> {
+        Time::next(self)
+    }
+}
+
+/// Times of day which HHMMSS digits sum up to `self.0`, in chronological order.
+///
+/// Like the dates, only the seconds of a minute are checked,
+/// and only if the hour and the minute leave a reachable sum for them.
+#[derive(Debug)]
+pub struct TimesWithDigitSum(pub NonZeroU8);
+new_expect!(TimesWithDigitSum);
+
+impl TimesWithDigitSum {
+    /// All the times in the range, in order.
+    pub fn get_times<R: RangeBounds<Time>>(
+        &self,
+        range: R,
+    ) -> impl Iterator<Item = Time> + use<R> {
+        fields::in_range(self.0, range)
+    }
+
+    /// Every minute is counted at once, by the seconds with the sum that the hour and the minute leave.
+    pub fn count(&self, range: impl RangeBounds<Time>) -> u64 {
+        fields::count(self.0, range)
+    }
+
+    /// The first time strictly after the given one, on the same day.
+    pub fn next_after(&self, time: Time) -> Option<Time> {
+        fields::walk_from(self.0, time.next()?).next()
+    }
+}
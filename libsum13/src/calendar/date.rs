@@ -0,0 +1,164 @@
+use std::{
+    fmt,
+    num::NonZeroU8,
+    ops::{RangeBounds, RangeInclusive},
+};
+
+use super::fields::{self, Fields};
+use crate::{DigitSum, new_expect};
+
+/// A day of the proleptic Gregorian calendar, from 0001-01-01 to 9999-12-31.
+///
+/// The fields go from the most significant, so the derived order is the chronological one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    pub const MIN: Date = Date {
+        year: 1,
+        month: 1,
+        day: 1,
+    };
+    pub const MAX: Date = Date {
+        year: 9999,
+        month: 12,
+        day: 31,
+    };
+
+    /// `None` if there is no such day.
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Self> {
+        let valid = (1..=9999).contains(&year)
+            && (1..=12).contains(&month)
+            && (1..=days_in_month(year, month)).contains(&day);
+
+        valid.then_some(Self { year, month, day })
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// The following day, if it is still in the calendar.
+    pub fn next(self) -> Option<Self> {
+        if self.day < days_in_month(self.year, self.month) {
+            Some(Self {
+                day: self.day + 1,
+                ..self
+            })
+        } else if self.month < 12 {
+            Some(Self {
+                month: self.month + 1,
+                day: 1,
+                ..self
+            })
+        } else if self.year < 9999 {
+            Some(Self {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+            })
+        } else {
+            None
+        }
+    }
+
+    pub fn digits_sum(&self) -> u64 {
+        self.as_u64().digits_sum()
+    }
+
+    /// The date written as YYYYMMDD.
+    pub fn as_u64(&self) -> u64 {
+        self.year as u64 * 10_000 + self.month as u64 * 100 + self.day as u64
+    }
+}
+
+impl DigitSum for Date {
+    fn digits_sum(&self) -> u64 {
+        Date::digits_sum(self)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl Fields for Date {
+    const MIN: Self = Date::MIN;
+    const HIGH: RangeInclusive<u16> = 1..=9999;
+    const MIDDLE: RangeInclusive<u8> = 1..=12;
+    /// A day has at most 2 + 9 = 11 as its digits sum
+    const MAX_LOW_SUM: u64 = 11;
+
+    fn low(year: u16, month: u8) -> RangeInclusive<u8> {
+        1..=days_in_month(year, month)
+    }
+
+    fn fields(self) -> (u16, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    fn from_fields(year: u16, month: u8, day: u8) -> Self {
+        Self { year, month, day }
+    }
+
+    fn next(self) -> Option<Self> {
+        Date::next(self)
+    }
+}
+
+pub fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4)
+        && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+pub fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Dates which YYYYMMDD digits sum up to `self.0`, in chronological order.
+///
+/// The date is a mixed-radix number: the years, the months and the days, with the month lengths
+/// depending on the year. So instead of walking day by day, only the days of a month are checked,
+/// and only if the year and the month leave a reachable sum for them.
+#[derive(Debug)]
+pub struct DatesWithDigitSum(pub NonZeroU8);
+new_expect!(DatesWithDigitSum);
+
+impl DatesWithDigitSum {
+    /// All the dates in the range, in order.
+    pub fn get_dates<R: RangeBounds<Date>>(
+        &self,
+        range: R,
+    ) -> impl Iterator<Item = Date> + use<R> {
+        fields::in_range(self.0, range)
+    }
+
+    /// Every month is counted at once, by the days with the sum that the year and the month leave.
+    pub fn count(&self, range: impl RangeBounds<Date>) -> u64 {
+        fields::count(self.0, range)
+    }
+
+    /// The first date strictly after the given one.
+    pub fn next_after(&self, date: Date) -> Option<Date> {
+        fields::walk_from(self.0, date.next()?).next()
+    }
+}
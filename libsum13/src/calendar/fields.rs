@@ -0,0 +1,136 @@
+use std::{
+    num::NonZeroU8,
+    ops::{Bound, RangeBounds, RangeInclusive},
+};
+
+use crate::DigitSum;
+
+/// The lowest field has at most two digits, so its digits sum is at most 18.
+const LOW_END: usize = 100;
+const LOW_SUMS: usize = 19;
+
+/// Three fields from the most significant one, like YYYY-MM-DD or HH:MM:SS.
+///
+/// The range of the lowest field may depend on the higher ones, like the month lengths do.
+pub(super) trait Fields: Copy + Ord {
+    const MIN: Self;
+    const HIGH: RangeInclusive<u16>;
+    const MIDDLE: RangeInclusive<u8>;
+    /// No value of the lowest field has a greater digits sum.
+    const MAX_LOW_SUM: u64;
+
+    fn low(high: u16, middle: u8) -> RangeInclusive<u8>;
+
+    fn fields(self) -> (u16, u8, u8);
+
+    fn from_fields(high: u16, middle: u8, low: u8) -> Self;
+
+    /// The following value, if there is one.
+    fn next(self) -> Option<Self>;
+}
+
+/// The higher fields from `(high, middle)` on, with the sum that is left for the lowest one.
+fn prefixes<F: Fields>(
+    sum: NonZeroU8,
+    (high, middle): (u16, u8),
+) -> impl Iterator<Item = (u16, u8, u64)> {
+    (high..=*F::HIGH.end())
+        .flat_map(|high| F::MIDDLE.map(move |middle| (high, middle)))
+        .skip_while(move |prefix| *prefix < (high, middle))
+        .filter_map(move |(high, middle)| {
+            let left = (sum.get() as u64).checked_sub(
+                (high as u64).digits_sum() + (middle as u64).digits_sum(),
+            )?;
+
+            (left <= F::MAX_LOW_SUM).then_some((high, middle, left))
+        })
+}
+
+/// All the values from `start` on which digits sum up to `sum`.
+pub(super) fn walk_from<F: Fields>(
+    sum: NonZeroU8,
+    start: F,
+) -> impl Iterator<Item = F> {
+    let (high, middle, _) = start.fields();
+
+    prefixes::<F>(sum, (high, middle))
+        .flat_map(|(high, middle, left)| {
+            F::low(high, middle)
+                .filter(move |low| (*low as u64).digits_sum() == left)
+                .map(move |low| F::from_fields(high, middle, low))
+        })
+        .skip_while(move |value| *value < start)
+}
+
+/// All the values in the range which digits sum up to `sum`, in order.
+pub(super) fn in_range<F: Fields, R: RangeBounds<F>>(
+    sum: NonZeroU8,
+    range: R,
+) -> impl Iterator<Item = F> + use<F, R> {
+    let end = (Bound::Unbounded, range.end_bound().cloned());
+
+    first(range.start_bound())
+        .map(|start| walk_from(sum, start))
+        .into_iter()
+        .flatten()
+        .take_while(move |value| end.contains(value))
+}
+
+/// The number of the values in the range which digits sum up to `sum`.
+pub(super) fn count<F: Fields>(
+    sum: NonZeroU8,
+    range: impl RangeBounds<F>,
+) -> u64 {
+    let end = match range.end_bound() {
+        Bound::Included(end) => end.next(),
+        Bound::Excluded(end) => Some(*end),
+        Bound::Unbounded => None,
+    };
+
+    count_before(sum, end)
+        .saturating_sub(count_before(sum, first(range.start_bound())))
+}
+
+/// The first value in the range, if there is one.
+fn first<F: Fields>(start: Bound<&F>) -> Option<F> {
+    match start {
+        Bound::Included(start) => Some(*start),
+        Bound::Excluded(start) => start.next(),
+        Bound::Unbounded => Some(F::MIN),
+    }
+}
+
+/// The number of the values before `end`, or of all of them if it's past the last one.
+///
+/// Every prefix before the one of `end` counts all of its lowest fields at once,
+/// by the number of the values with each digits sum below the end of the field.
+fn count_before<F: Fields>(sum: NonZeroU8, end: Option<F>) -> u64 {
+    // below[v][s] is the number of the values in range `0..v` with digits sum `s`
+    let mut below = [[0; LOW_SUMS]; LOW_END + 1];
+    for value in 0..LOW_END {
+        below[value + 1] = below[value];
+        below[value + 1][(value as u64).digits_sum() as usize] += 1;
+    }
+
+    // Past the last value, every prefix is before the end
+    let (end_high, end_middle, end_low) =
+        end.map_or((u16::MAX, 0, 0), F::fields);
+    let (high, middle, _) = F::MIN.fields();
+
+    prefixes::<F>(sum, (high, middle))
+        .take_while(|(high, middle, _)| {
+            (*high, *middle) <= (end_high, end_middle)
+        })
+        .map(|(high, middle, left)| {
+            let low = F::low(high, middle);
+            let low_end = if (high, middle) < (end_high, end_middle) {
+                *low.end() + 1
+            } else {
+                end_low
+            };
+
+            below[low_end as usize][left as usize]
+                - below[*low.start() as usize][left as usize]
+        })
+        .sum()
+}
@@ -1,3 +1,497 @@
 //! Here are all the functions that use combinatorics to calculate all numbers that have digits sum of 13
 
+mod length;
+mod lexicographic;
+mod walk;
+
+use length::count_completions;
+pub use length::{Length, MAX_LENGTH, WithDigitSumOfLength};
+pub use lexicographic::LexicographicWithDigitSum;
+#[cfg(feature = "std")]
+pub(crate) use walk::Decimal;
+pub(crate) use walk::{DigitRule, DigitWalk, count_below};
+
 pub struct Combinatorics;
+
+/// u64 has at most 20 digits.
+pub const MAX_DIGITS: usize = 20;
+pub const MAX_SUM: usize = 9 * MAX_DIGITS;
+
+/// `DIGIT_STRINGS[d][m]` is T(d, m): the number of `d`-digit strings (leading zeros allowed)
+/// which digits sum up to `m`.
+///
+/// It's the same as the number of integers in range `0..10^d` with digits sum `m`.
+pub static DIGIT_STRINGS: [[u64; MAX_SUM + 1]; MAX_DIGITS + 1] = {
+    let mut table = [[0u64; MAX_SUM + 1]; MAX_DIGITS + 1];
+    table[0][0] = 1;
+
+    let mut digits = 1;
+    while digits <= MAX_DIGITS {
+        let mut sum = 0;
+        while sum <= 9 * digits {
+            let mut digit = 0;
+            while digit <= 9 && digit <= sum {
+                table[digits][sum] += table[digits - 1][sum - digit];
+                digit += 1;
+            }
+            sum += 1;
+        }
+        digits += 1;
+    }
+
+    table
+};
+
+/// T(d, m) which is zero for everything outside of the table.
+pub const fn digit_strings(digits: usize, sum: u32) -> u64 {
+    if digits > MAX_DIGITS || sum as usize > MAX_SUM {
+        0
+    } else {
+        DIGIT_STRINGS[digits][sum as usize]
+    }
+}
+
+/// Digits of `end` padded to `MAX_DIGITS`, the most significant first.
+pub(crate) const fn end_digits(end: u64) -> [u32; MAX_DIGITS] {
+    let mut digits = [0; MAX_DIGITS];
+    let mut end = end;
+    let mut position = MAX_DIGITS;
+
+    while position > 0 {
+        position -= 1;
+        digits[position] = (end % 10) as u32;
+        end /= 10;
+    }
+
+    digits
+}
+
+/// The number of values in range `0..end` which digits sum up to `sum`.
+///
+/// While the digits are the same as in `end`, every smaller digit frees all the positions after it.
+/// It's a `const fn`, so the counts can size arrays: `[u64; count_digit_sum(13, 1000) as usize]`.
+pub const fn count_digit_sum(sum: u8, end: u64) -> u64 {
+    let digits = end_digits(end);
+    let mut left = sum as u32;
+    let mut count = 0;
+    let mut position = 0;
+
+    while position < MAX_DIGITS {
+        let after = MAX_DIGITS - position - 1;
+        let end_digit = digits[position];
+        let mut digit = 0;
+
+        while digit < end_digit && digit <= left {
+            count += digit_strings(after, left - digit);
+            digit += 1;
+        }
+
+        if end_digit > left {
+            break;
+        }
+
+        left -= end_digit;
+        position += 1;
+    }
+
+    count
+}
+
+/// The `n`-th value in range `0..end` which digits sum up to `sum`, the same walk as in
+/// `count_digit_sum`: every digit skips the numbers that start with the smaller ones.
+pub const fn nth_digit_sum(sum: u8, end: u64, mut n: u64) -> Option<u64> {
+    let digits = end_digits(end);
+    let mut left = sum as u32;
+    let mut value = 0;
+    let mut tight = true;
+    let mut position = 0;
+
+    while position < MAX_DIGITS {
+        let after = MAX_DIGITS - position - 1;
+        let end_digit = digits[position];
+        // While tight, the digit of `end` itself is handled separately
+        let bound = if tight { end_digit } else { 10 };
+        let mut digit = 0;
+
+        while digit < bound && digit <= left {
+            let count = digit_strings(after, left - digit);
+
+            if n < count {
+                break;
+            }
+
+            n -= count;
+            digit += 1;
+        }
+
+        if digit < bound && digit <= left {
+            tight = false;
+        } else if tight && end_digit <= left {
+            // Only the digit of `end` is left, and it must fit into the sum
+            digit = end_digit;
+        } else {
+            return None;
+        }
+
+        value = value * 10 + digit as u64;
+        left -= digit;
+        position += 1;
+    }
+
+    // All the digits are the same as in `end`, which is not in the range
+    if !tight && left == 0 {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// `histogram(end)[m]` is the number of values in range `0..end` with digits sum `m`.
+///
+/// The same walk over the digits of `end` as in counting a single sum, just for all of them at once:
+/// every digit smaller than the one of `end` frees all the positions after it.
+pub fn histogram(end: u64) -> [u64; MAX_SUM + 1] {
+    let mut histogram = [0; MAX_SUM + 1];
+    let mut prefix = 0;
+
+    for (position, end_digit) in end_digits(end).into_iter().enumerate() {
+        let after = MAX_DIGITS - position - 1;
+        let end_digit = end_digit as usize;
+
+        for digit in 0..end_digit {
+            for rest in 0..=9 * after {
+                histogram[prefix + digit + rest] += DIGIT_STRINGS[after][rest];
+            }
+        }
+
+        prefix += end_digit;
+    }
+
+    histogram
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU8;
+
+    use crate::{DigitSum, integer, traits::SumSequencer};
+
+    use super::{
+        Length, LexicographicWithDigitSum, WithDigitSumOfLength,
+        count_digit_sum, digit_strings, histogram, nth_digit_sum,
+    };
+
+    #[test]
+    fn test_digit_strings() {
+        for digits in 0..=6 {
+            let end = 10u64.pow(digits as u32);
+
+            for sum in 0..=60 {
+                assert_eq!(
+                    digit_strings(digits, sum),
+                    (0..end)
+                        .filter(|value| value.digits_sum() == sum as u64)
+                        .count() as u64,
+                    "digits {digits}, sum {sum}"
+                );
+            }
+        }
+
+        assert_eq!(digit_strings(20, 0), 1);
+        assert_eq!(digit_strings(20, 180), 1);
+        assert_eq!(digit_strings(20, 181), 0);
+        assert_eq!(digit_strings(21, 1), 0);
+    }
+
+    const COUNT: u64 = count_digit_sum(13, 1_000_000);
+    const FIRST: [u64; 3] = [
+        integer::get_initial(NonZeroU8::new(13).unwrap()),
+        integer::get_initial(NonZeroU8::new(1).unwrap()),
+        integer::get_initial(NonZeroU8::new(27).unwrap()),
+    ];
+    static BELOW_1000: [u64; count_digit_sum(13, 1000) as usize] = {
+        let mut values = [0; count_digit_sum(13, 1000) as usize];
+        let mut n = 0;
+
+        while n < values.len() {
+            values[n] = nth_digit_sum(13, 1000, n as u64).unwrap();
+            n += 1;
+        }
+
+        values
+    };
+
+    #[test]
+    fn test_const_count_and_nth() {
+        let expected = integer::WithDigitSum::new(13)
+            .get_ints(u32::MAX)
+            .take_while(|value| *value < 1_000_000)
+            .collect::<Vec<_>>();
+
+        assert_eq!(COUNT, expected.len() as u64);
+        assert_eq!(FIRST, [49, 1, 999]);
+        assert_eq!(BELOW_1000.as_slice(), &expected[..BELOW_1000.len()]);
+
+        for end in [0, 1, 49, 50, 12_345, 1_000_000] {
+            for sum in 0..=60 {
+                let expected = (0..end)
+                    .filter(|value: &u64| value.digits_sum() == sum as u64)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(count_digit_sum(sum, end), expected.len() as u64);
+
+                if end == 12_345 {
+                    for (n, value) in expected.iter().enumerate() {
+                        assert_eq!(
+                            nth_digit_sum(sum, end, n as u64),
+                            Some(*value)
+                        );
+                    }
+                }
+
+                assert_eq!(
+                    nth_digit_sum(sum, end, expected.len() as u64),
+                    None
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_histogram() {
+        for end in (0..=1000).chain([12_345, 100_000, 987_654]) {
+            let mut expected = [0; 181];
+            (0..end)
+                .for_each(|value| expected[value.digits_sum() as usize] += 1);
+
+            assert_eq!(histogram(end), expected, "end {end}");
+        }
+
+        assert_eq!(histogram(u64::MAX).iter().sum::<u64>(), u64::MAX);
+    }
+
+    #[test]
+    fn test_exact_length() {
+        for length in 1..=6 {
+            let start = 10u64.pow(length as u32 - 1);
+            let end = start * 10;
+
+            for sum in (1..=9 * length).filter_map(NonZeroU8::new) {
+                let expected = (start..end)
+                    .filter(|value| value.digits_sum() == sum.get() as u64)
+                    .collect::<Vec<_>>();
+
+                let ints = WithDigitSumOfLength {
+                    sum,
+                    length: Length::Exactly(length),
+                };
+
+                assert_eq!(ints.get_ints().collect::<Vec<_>>(), expected);
+                assert_eq!(ints.count(), expected.len() as u64);
+
+                for (i, value) in expected.iter().enumerate() {
+                    assert_eq!(ints.nth(i as u64), Some(*value));
+                }
+                assert_eq!(ints.nth(expected.len() as u64), None);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Length must be from 1 to 20 digits")]
+    fn test_zero_length() {
+        WithDigitSumOfLength {
+            sum: NonZeroU8::new(1).unwrap(),
+            length: Length::Exactly(0),
+        }
+        .count();
+    }
+
+    #[test]
+    fn test_at_most_length() {
+        let sum = NonZeroU8::new(13).unwrap();
+
+        for length in 1..=8 {
+            let ints = WithDigitSumOfLength {
+                sum,
+                length: Length::AtMost(length),
+            };
+
+            let expected = integer::WithDigitSum(sum)
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < 10u64.pow(length as u32))
+                .collect::<Vec<_>>();
+
+            assert_eq!(ints.get_ints().collect::<Vec<_>>(), expected);
+            assert_eq!(ints.count(), expected.len() as u64);
+
+            for i in (0..expected.len()).step_by(97) {
+                assert_eq!(ints.nth(i as u64), Some(expected[i]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_longest_length() {
+        let ints = WithDigitSumOfLength {
+            sum: NonZeroU8::new(3).unwrap(),
+            length: Length::Exactly(19),
+        };
+
+        let all = ints.get_ints().collect::<Vec<_>>();
+
+        assert_eq!(all.len() as u64, ints.count());
+        assert_eq!(all.first(), Some(&1_000_000_000_000_000_002));
+        assert_eq!(ints.nth(ints.count() - 1), Some(3_000_000_000_000_000_000));
+        assert_eq!(all.last(), Some(&3_000_000_000_000_000_000));
+
+        // The 20-digit numbers only go up to u64::MAX
+        let ints = WithDigitSumOfLength {
+            sum: NonZeroU8::new(2).unwrap(),
+            length: Length::Exactly(20),
+        };
+        let expected = (0..19)
+            .map(|power| 10_000_000_000_000_000_000 + 10u64.pow(power))
+            .collect::<Vec<_>>();
+
+        assert_eq!(ints.get_ints().collect::<Vec<_>>(), expected);
+        assert_eq!(ints.count(), expected.len() as u64);
+        assert_eq!(ints.nth(18), Some(expected[18]));
+        assert_eq!(ints.nth(19), None);
+
+        for sum in (1..=4).filter_map(NonZeroU8::new) {
+            let ints = WithDigitSumOfLength {
+                sum,
+                length: Length::Exactly(20),
+            };
+            let all = ints.get_ints().collect::<Vec<_>>();
+
+            assert_eq!(all.len() as u64, ints.count(), "sum {sum}");
+            assert!(all.is_sorted());
+            assert!(all.iter().all(|value| {
+                *value >= 10_000_000_000_000_000_000
+                    && value.digits_sum() == sum.get() as u64
+            }));
+
+            for (i, value) in all.iter().enumerate() {
+                assert_eq!(ints.nth(i as u64), Some(*value));
+            }
+            assert_eq!(ints.nth(all.len() as u64), None);
+        }
+
+        // u64::MAX is the last one with its digits sum
+        let ints = WithDigitSumOfLength {
+            sum: NonZeroU8::new(87).unwrap(),
+            length: Length::AtMost(20),
+        };
+
+        assert_eq!(ints.nth(ints.count() - 1), Some(u64::MAX));
+        assert_eq!(ints.nth(ints.count()), None);
+    }
+
+    #[test]
+    fn test_lexicographic() {
+        for (sum, length) in [
+            (1, Length::AtMost(5)),
+            (13, Length::AtMost(6)),
+            (13, Length::Exactly(4)),
+            (20, Length::AtMost(4)),
+            (5, Length::Exactly(1)),
+            (40, Length::AtMost(5)),
+        ] {
+            let max = match length {
+                Length::Exactly(length) | Length::AtMost(length) => length,
+            };
+            let min = match length {
+                Length::Exactly(length) => length,
+                Length::AtMost(_) => 1,
+            };
+
+            let mut expected = (10u64.pow(min as u32 - 1)
+                ..10u64.pow(max as u32))
+                .filter(|value| value.digits_sum() == sum as u64)
+                .map(|value| value.to_string())
+                .collect::<Vec<_>>();
+            expected.sort();
+            let expected = expected
+                .iter()
+                .map(|value| value.parse::<u64>().unwrap())
+                .collect::<Vec<_>>();
+
+            let ints = LexicographicWithDigitSum {
+                sum: NonZeroU8::new(sum).unwrap(),
+                length,
+            };
+
+            assert_eq!(
+                ints.get_ints().collect::<Vec<_>>(),
+                expected,
+                "sum {sum}, {length:?}"
+            );
+            assert_eq!(ints.count(), expected.len() as u64);
+            assert_eq!(ints.nth(expected.len() as u64), None);
+
+            for (i, value) in expected.iter().enumerate() {
+                assert_eq!(ints.nth(i as u64), Some(*value));
+                assert_eq!(ints.rank(*value), Some(i as u64));
+            }
+        }
+
+        let ints = LexicographicWithDigitSum {
+            sum: NonZeroU8::new(13).unwrap(),
+            length: Length::AtMost(19),
+        };
+
+        assert_eq!(
+            ints.get_ints().take(4).collect::<Vec<_>>(),
+            vec![
+                1_000_000_000_000_000_039,
+                1_000_000_000_000_000_048,
+                1_000_000_000_000_000_057,
+                1_000_000_000_000_000_066
+            ]
+        );
+        assert!(ints.rank(1039) < ints.rank(139));
+        assert!(ints.rank(49) < ints.rank(490));
+        assert_eq!(ints.rank(14), None);
+        assert_eq!(ints.rank(0), None);
+        assert_eq!(ints.nth(ints.count() - 1), Some(9_400_000_000_000_000_000));
+        assert_eq!(
+            ints.rank(9_400_000_000_000_000_000),
+            Some(ints.count() - 1)
+        );
+
+        // The 20-digit numbers only go up to u64::MAX
+        let ints = LexicographicWithDigitSum {
+            sum: NonZeroU8::new(87).unwrap(),
+            length: Length::AtMost(20),
+        };
+        let rank = ints.rank(u64::MAX).unwrap();
+
+        assert_eq!(ints.nth(rank), Some(u64::MAX));
+        assert_eq!(ints.nth(ints.count()), None);
+
+        let ints = LexicographicWithDigitSum {
+            sum: NonZeroU8::new(2).unwrap(),
+            length: Length::AtMost(20),
+        };
+        let mut expected = (0..20)
+            .flat_map(|high| (0..=high).map(move |low| (high, low)))
+            .map(|(high, low)| 10u128.pow(high) + 10u128.pow(low))
+            .filter(|value| *value <= u64::MAX as u128)
+            .map(|value| value.to_string())
+            .collect::<Vec<_>>();
+        expected.sort();
+        let expected = expected
+            .iter()
+            .map(|value| value.parse::<u64>().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(ints.get_ints().collect::<Vec<_>>(), expected);
+
+        for (i, value) in expected.iter().enumerate() {
+            assert_eq!(ints.nth(i as u64), Some(*value));
+            assert_eq!(ints.rank(*value), Some(i as u64));
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use core::{cmp::Ordering, num::NonZeroU8, ops::RangeInclusive};
+
+use super::{
+    DigitRule, DigitWalk, MAX_DIGITS, count_below, digit_strings, end_digits,
+};
+
+/// Not every 20-digit number fits into u64, so those only go up to `u64::MAX`.
+pub const MAX_LENGTH: u8 = MAX_DIGITS as u8;
+
+/// Number of digits in the decimal representation, without leading zeros.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Length {
+    Exactly(u8),
+    AtMost(u8),
+}
+
+impl Length {
+    pub(super) fn lengths(self) -> RangeInclusive<u8> {
+        let lengths = match self {
+            Self::Exactly(length) => length..=length,
+            Self::AtMost(length) => 1..=length,
+        };
+
+        assert!(
+            *lengths.start() >= 1 && *lengths.end() <= MAX_LENGTH,
+            "Length must be from 1 to {MAX_LENGTH} digits"
+        );
+
+        lengths
+    }
+}
+
+/// All the numbers of the given length which digits sum up to `sum`, e.g. all the 8-digit numbers.
+#[derive(Debug)]
+pub struct WithDigitSumOfLength {
+    pub sum: NonZeroU8,
+    pub length: Length,
+}
+
+impl WithDigitSumOfLength {
+    pub fn get_ints(&self) -> impl Iterator<Item = u64> + use<> {
+        let sum = self.sum;
+
+        self.length
+            .lengths()
+            .flat_map(move |length| with_exact_length(sum, length))
+    }
+
+    pub fn count(&self) -> u64 {
+        let sum = self.sum.get() as u32;
+
+        self.length
+            .lengths()
+            .map(|length| count_exact_length(sum, length))
+            .sum()
+    }
+
+    /// The same as `self.get_ints().nth(n)`, but without walking through the first `n` numbers.
+    pub fn nth(&self, mut n: u64) -> Option<u64> {
+        let sum = self.sum.get() as u32;
+
+        for length in self.length.lengths() {
+            let count = count_exact_length(sum, length);
+
+            if n < count {
+                return Some(nth_exact_length(sum, length, n));
+            }
+
+            n -= count;
+        }
+
+        None
+    }
+}
+
+fn count_exact_length(sum: u32, length: u8) -> u64 {
+    (1..=u32::min(9, sum))
+        .map(|first| {
+            count_completions(first as u64, 1, length as usize, sum - first)
+        })
+        .sum()
+}
+
+/// How many of the `total`-digit numbers start with the `length`-digit `prefix`
+/// and have the rest of the digits summing up to `left`.
+///
+/// The 20-digit ones must not go over `u64::MAX`, so the prefix is compared to its digits.
+pub(super) fn count_completions(
+    prefix: u64,
+    length: usize,
+    total: usize,
+    left: u32,
+) -> u64 {
+    let positions = total - length;
+
+    if total < MAX_DIGITS {
+        return digit_strings(positions, left);
+    }
+
+    let power = 10u64.pow(positions as u32);
+
+    match prefix.cmp(&(u64::MAX / power)) {
+        Ordering::Less => digit_strings(positions, left),
+        Ordering::Equal => {
+            // The rest goes up to the lower digits of u64::MAX, including them
+            let (below, rest) = count_below(
+                &end_digits(u64::MAX)[MAX_DIGITS - positions..],
+                left,
+                |left, position, digit| {
+                    let after = positions - position - 1;
+
+                    left.checked_sub(digit)
+                        .map_or(0, |rest| digit_strings(after, rest))
+                },
+                |left, _, digit| left.checked_sub(digit),
+            );
+
+            below + (rest == Some(0)) as u64
+        }
+        Ordering::Greater => 0,
+    }
+}
+
+/// Walk down the digits: each digit skips all the numbers that start with a smaller one.
+///
+/// The numbers go in order, so the first `count_exact_length` of them all fit into u64.
+fn nth_exact_length(mut sum: u32, length: u8, mut n: u64) -> u64 {
+    let mut value = 0;
+
+    for position in 0..length as usize {
+        let after = length as usize - position - 1;
+        let first = if position == 0 { 1 } else { 0 };
+
+        for digit in first..=u32::min(9, sum) {
+            let count = digit_strings(after, sum - digit);
+
+            if n < count {
+                value = value * 10 + digit as u64;
+                sum -= digit;
+                break;
+            }
+
+            n -= count;
+        }
+    }
+
+    value
+}
+
+fn with_exact_length(
+    sum: NonZeroU8,
+    length: u8,
+) -> impl Iterator<Item = u64> + use<> {
+    // The sparse sums skip way too many hundreds for IntsWithDigitSumInBounds,
+    // so the next number is found digit by digit instead
+    DigitWalk::new(Rule {
+        sum: sum.get() as u32,
+        length: length as usize,
+    })
+}
+
+/// The state is the sum that is left and the number of the digits that are placed.
+struct Rule {
+    sum: u32,
+    length: usize,
+}
+
+impl DigitRule for Rule {
+    type State = (u32, usize);
+
+    fn start(&self) -> Self::State {
+        (self.sum, 0)
+    }
+
+    fn place(
+        &self,
+        (left, placed): &Self::State,
+        digit: u32,
+        after: usize,
+    ) -> Option<Self::State> {
+        let left = left.checked_sub(digit)?;
+
+        (placed + 1 + after == self.length && left <= 9 * after as u32)
+            .then_some((left, placed + 1))
+    }
+}
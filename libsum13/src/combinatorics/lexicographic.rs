@@ -0,0 +1,208 @@
+use core::num::NonZeroU8;
+
+use super::{Length, MAX_DIGITS, MAX_LENGTH, count_completions, end_digits};
+
+/// Numbers of the given length which digits sum up to `sum`, in the order of their decimal strings:
+/// 1039 goes before 139, and 49 goes before 490.
+///
+/// That's the depth-first order of the digit trie, where every number is its own prefix's child.
+/// A branch is only entered if the sum that is left still fits into the remaining positions.
+#[derive(Debug)]
+pub struct LexicographicWithDigitSum {
+    pub sum: NonZeroU8,
+    pub length: Length,
+}
+
+impl LexicographicWithDigitSum {
+    pub fn get_ints(&self) -> impl Iterator<Item = u64> + use<> {
+        let sum = self.sum.get() as u32;
+        let lengths = self.length.lengths();
+        let (shortest, longest) = (*lengths.start(), *lengths.end());
+        let mut trie = Trie::new(sum, longest);
+
+        core::iter::from_fn(move || {
+            while trie.advance() {
+                // The longest numbers only go up to u64::MAX
+                if trie.left == 0
+                    && trie.length >= shortest as usize
+                    && let Some(value) = trie.value()
+                {
+                    return Some(value);
+                }
+            }
+
+            None
+        })
+    }
+
+    pub fn count(&self) -> u64 {
+        let sum = self.sum.get() as u32;
+
+        (1..=u32::min(9, sum))
+            .map(|first| self.subtree(first as u64, 1, sum - first))
+            .sum()
+    }
+
+    /// The same as `self.get_ints().nth(n)`, but the whole branches are skipped by their size.
+    pub fn nth(&self, mut n: u64) -> Option<u64> {
+        let sum = self.sum.get() as u32;
+        let shortest = *self.length.lengths().start() as usize;
+        let mut value = 0;
+        let mut left = sum;
+        let mut length = 0;
+
+        loop {
+            if length > 0 && left == 0 && length >= shortest {
+                if n == 0 {
+                    return Some(value);
+                }
+
+                n -= 1;
+            }
+
+            let first = if length == 0 { 1 } else { 0 };
+
+            let digit = (first..=u32::min(9, left)).find(|digit| {
+                // A child that doesn't fit into u64 has nothing in it
+                let size = value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(*digit as u64))
+                    .map_or(0, |child| {
+                        self.subtree(child, length + 1, left - digit)
+                    });
+
+                if n < size {
+                    true
+                } else {
+                    n -= size;
+                    false
+                }
+            })?;
+
+            value = value * 10 + digit as u64;
+            left -= digit;
+            length += 1;
+        }
+    }
+
+    /// Position of `value` in the order, or `None` if it's not there at all.
+    pub fn rank(&self, value: u64) -> Option<u64> {
+        let sum = self.sum.get() as u32;
+        let lengths = self.length.lengths();
+        let padded = end_digits(value);
+        let digits = &padded[MAX_DIGITS - Self::digits_count(value)..];
+
+        if value == 0 || !lengths.contains(&(digits.len() as u8)) {
+            return None;
+        }
+
+        let mut rank = 0;
+        let mut left = sum;
+        let mut prefix = 0;
+
+        for (length, digit) in digits.iter().copied().enumerate() {
+            // The prefix itself goes before all of its children
+            if length > 0 && left == 0 && length >= *lengths.start() as usize {
+                rank += 1;
+            }
+
+            let first = if length == 0 { 1 } else { 0 };
+
+            rank += (first..u32::min(digit, left + 1))
+                .map(|smaller| {
+                    let child = prefix * 10 + smaller as u64;
+
+                    self.subtree(child, length + 1, left - smaller)
+                })
+                .sum::<u64>();
+
+            left = left.checked_sub(digit)?;
+            prefix = prefix * 10 + digit as u64;
+        }
+
+        (left == 0).then_some(rank)
+    }
+
+    fn digits_count(value: u64) -> usize {
+        value.checked_ilog10().map_or(0, |log| log as usize + 1)
+    }
+
+    /// How many of the numbers start with the given `prefix` of `length` digits
+    /// that leaves `left` for the rest of the digits.
+    fn subtree(&self, prefix: u64, length: usize, left: u32) -> u64 {
+        let lengths = self.length.lengths();
+        let shortest = usize::max(length, *lengths.start() as usize);
+
+        (shortest..=*lengths.end() as usize)
+            .map(|total| count_completions(prefix, length, total, left))
+            .sum()
+    }
+}
+
+/// The current node of the digit trie: the digits of the prefix and the sum that is left.
+struct Trie {
+    digits: [u8; MAX_LENGTH as usize],
+    length: usize,
+    left: u32,
+    longest: usize,
+}
+
+impl Trie {
+    fn new(sum: u32, longest: u8) -> Self {
+        Self {
+            digits: [0; MAX_LENGTH as usize],
+            length: 0,
+            left: sum,
+            longest: longest as usize,
+        }
+    }
+
+    fn value(&self) -> Option<u64> {
+        self.digits[..self.length]
+            .iter()
+            .try_fold(0u64, |acc, digit| {
+                acc.checked_mul(10)?.checked_add(*digit as u64)
+            })
+    }
+
+    /// The remaining sum must fit into the positions that are left, like in `count_addition`.
+    fn fits(&self, length: usize, left: u32) -> bool {
+        left <= 9 * (self.longest - length) as u32
+    }
+
+    /// Move to the next node in the depth-first order. Returns false once the whole trie is walked.
+    fn advance(&mut self) -> bool {
+        // Go down to the first child if there is one
+        if self.length < self.longest {
+            let first = if self.length == 0 { 1 } else { 0 };
+            let child = (first..=u32::min(9, self.left))
+                .find(|digit| self.fits(self.length + 1, self.left - digit));
+
+            if let Some(digit) = child {
+                self.digits[self.length] = digit as u8;
+                self.length += 1;
+                self.left -= digit;
+                return true;
+            }
+        }
+
+        // Otherwise go to the next sibling of the node or of one of its ancestors
+        while self.length > 0 {
+            let digit = self.digits[self.length - 1] as u32;
+            self.length -= 1;
+            self.left += digit;
+
+            let sibling = (digit + 1..=u32::min(9, self.left))
+                .find(|digit| self.fits(self.length + 1, self.left - digit));
+
+            if let Some(digit) = sibling {
+                self.digits[self.length] = digit as u8;
+                self.length += 1;
+                self.left -= digit;
+                return true;
+            }
+        }
+
+        false
+    }
+}
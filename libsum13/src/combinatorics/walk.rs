@@ -0,0 +1,192 @@
+use super::MAX_DIGITS;
+#[cfg(feature = "std")]
+use super::end_digits;
+
+/// Digits of a number without the leading zeros, the most significant first.
+///
+/// Zero has no digits at all, so there is nothing below it to count.
+#[cfg(feature = "std")]
+pub(crate) struct Decimal {
+    digits: [u32; MAX_DIGITS],
+    length: usize,
+}
+
+#[cfg(feature = "std")]
+impl Decimal {
+    pub(crate) fn new(value: u64) -> Self {
+        Self {
+            digits: end_digits(value),
+            length: value.checked_ilog10().map_or(0, |log| log as usize + 1),
+        }
+    }
+
+    pub(crate) fn digits(&self) -> &[u32] {
+        &self.digits[MAX_DIGITS - self.length..]
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.length
+    }
+}
+
+/// Digit DP over the prefixes of `end`: count all the numbers below it that have as many digits.
+///
+/// For every prefix of `end`, `below(&state, position, digit)` counts the numbers that continue it
+/// with a smaller `digit`, and `place(state, position, digit)` moves past the digit of `end` itself.
+/// Once that one doesn't fit the constraints, no number starts with the prefix anymore.
+/// Returns the count and the state after all of `end_digits`, if all of them fit.
+pub(crate) fn count_below<S>(
+    end_digits: &[u32],
+    mut state: S,
+    mut below: impl FnMut(&S, usize, u32) -> u64,
+    mut place: impl FnMut(S, usize, u32) -> Option<S>,
+) -> (u64, Option<S>) {
+    let mut count = 0;
+
+    for (position, end_digit) in end_digits.iter().enumerate() {
+        count += (0..*end_digit)
+            .map(|digit| below(&state, position, digit))
+            .sum::<u64>();
+
+        let Some(next) = place(state, position, *end_digit) else {
+            return (count, None);
+        };
+
+        state = next;
+    }
+
+    (count, Some(state))
+}
+
+/// Constraints on the digits of a number, checked from the most significant one.
+pub(crate) trait DigitRule {
+    type State;
+
+    /// The state before the first digit.
+    fn start(&self) -> Self::State;
+
+    /// The state after `digit`, if it can be placed with `after` more positions to fill.
+    ///
+    /// The rule must only let through the digits that leave the rest of the positions fillable.
+    fn place(
+        &self,
+        state: &Self::State,
+        digit: u32,
+        after: usize,
+    ) -> Option<Self::State>;
+}
+
+/// All the numbers which digits follow the rule, in numeric order.
+///
+/// Every digit is checked to leave the rest of the positions fillable,
+/// so moving to the next number never needs to backtrack.
+pub(crate) struct DigitWalk<R> {
+    rule: R,
+    digits: [u8; MAX_DIGITS],
+    /// Zero once the walk is over
+    length: usize,
+}
+
+impl<R: DigitRule> DigitWalk<R> {
+    pub(crate) fn new(rule: R) -> Self {
+        let mut walk = Self {
+            rule,
+            digits: [0; MAX_DIGITS],
+            length: 0,
+        };
+
+        if !walk.grow() {
+            walk.length = 0;
+        }
+
+        walk
+    }
+
+    fn value(&self) -> Option<u64> {
+        self.digits[..self.length]
+            .iter()
+            .try_fold(0u64, |acc, digit| {
+                acc.checked_mul(10)?.checked_add(*digit as u64)
+            })
+    }
+
+    /// Move to the smallest number that is longer than the current one.
+    fn grow(&mut self) -> bool {
+        (self.length + 1..=MAX_DIGITS).any(|length| {
+            self.length = length;
+            self.fill_smallest(0, self.rule.start())
+        })
+    }
+
+    fn state_before(&self, position: usize) -> R::State {
+        self.digits[..position].iter().enumerate().fold(
+            self.rule.start(),
+            |state, (placed, digit)| {
+                self.rule
+                    .place(&state, *digit as u32, self.length - placed - 1)
+                    .expect("The digits before are already placed")
+            },
+        )
+    }
+
+    /// Fill positions `from..` with the smallest digits that follow the rule.
+    fn fill_smallest(&mut self, from: usize, mut state: R::State) -> bool {
+        for position in from..self.length {
+            let first = if position == 0 { 1 } else { 0 };
+            let after = self.length - position - 1;
+
+            let Some((digit, next)) = (first..=9).find_map(|digit| {
+                Some((digit, self.rule.place(&state, digit, after)?))
+            }) else {
+                return false;
+            };
+
+            self.digits[position] = digit as u8;
+            state = next;
+        }
+
+        true
+    }
+
+    /// Move to the next number in numeric order. Returns false once there are no more of them.
+    fn advance(&mut self) -> bool {
+        for position in (0..self.length).rev() {
+            let state = self.state_before(position);
+            let after = self.length - position - 1;
+
+            let next =
+                (self.digits[position] as u32 + 1..=9).find_map(|digit| {
+                    Some((digit, self.rule.place(&state, digit, after)?))
+                });
+
+            if let Some((digit, next)) = next {
+                self.digits[position] = digit as u8;
+                return self.fill_smallest(position + 1, next);
+            }
+        }
+
+        self.grow()
+    }
+}
+
+impl<R: DigitRule> Iterator for DigitWalk<R> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.length == 0 {
+            return None;
+        }
+
+        // Once a number doesn't fit, none of the following ones fit either
+        let Some(value) = self.value() else {
+            self.length = 0;
+            return None;
+        };
+
+        if !self.advance() {
+            self.length = 0;
+        }
+
+        Some(value)
+    }
+}
@@ -0,0 +1,277 @@
+//! Here are all the sequencers that yield the digits themselves instead of the numbers
+
+use core::num::NonZeroU8;
+
+use crate::combinatorics::{MAX_DIGITS, MAX_SUM};
+
+/// Which of the digit vectors are yielded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Composition {
+    /// Every number, in the same order as `integer::WithDigitSum`.
+    Positional,
+    /// Every multiset of digits only once, with the digits in non-increasing order.
+    ///
+    /// That's the greatest number made of the multiset, so there are no leading zeros,
+    /// and the multisets come in the increasing order of those numbers.
+    Multiset,
+}
+
+/// Digits of the numbers which digits sum up to the given number, most significant first.
+///
+/// The digits are changed in place from one number to the next one, so the vector is only lent:
+/// there is `next` like in an iterator, but its result must be dropped before the next call.
+#[derive(Debug, Clone)]
+pub struct DigitVectors {
+    composition: Composition,
+    /// `Positional` keeps the number in the end of the array, `Multiset` keeps it in the beginning
+    digits: [u8; MAX_DIGITS],
+    length: usize,
+    sum: u32,
+    started: bool,
+    finished: bool,
+}
+
+impl DigitVectors {
+    pub fn new(sum: NonZeroU8, composition: Composition) -> Self {
+        Self {
+            composition,
+            digits: [0; MAX_DIGITS],
+            length: 0,
+            sum: sum.get() as u32,
+            started: false,
+            finished: false,
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&[u8]> {
+        let found = match (self.composition, self.started) {
+            _ if self.finished || self.sum as usize > MAX_SUM => false,
+            (Composition::Positional, false) => {
+                self.fill_smallest(0, self.sum);
+                self.length = (self.sum as usize).div_ceil(9);
+                true
+            }
+            (Composition::Positional, true) => self.advance_positional(),
+            (Composition::Multiset, false) => self.grow_multiset(),
+            (Composition::Multiset, true) => self.advance_multiset(),
+        };
+
+        self.started = true;
+
+        if !found {
+            // There is nothing after the 20-digit numbers
+            self.finished = true;
+            return None;
+        }
+
+        Some(match self.composition {
+            Composition::Positional => &self.digits[MAX_DIGITS - self.length..],
+            Composition::Multiset => &self.digits[..self.length],
+        })
+    }
+
+    /// The same numbers as zero-padded arrays of `N` digits, while they fit.
+    pub fn fixed_width<const N: usize>(
+        mut self,
+    ) -> impl Iterator<Item = [u8; N]> {
+        core::iter::from_fn(move || {
+            let digits = self.next().filter(|digits| digits.len() <= N)?;
+            let mut fixed = [0; N];
+            fixed[N - digits.len()..].copy_from_slice(digits);
+
+            Some(fixed)
+        })
+    }
+
+    /// The lowest digit that can grow takes one from the digits below it,
+    /// and they get rearranged into the smallest number.
+    fn advance_positional(&mut self) -> bool {
+        let mut below = 0;
+
+        for position in (0..MAX_DIGITS).rev() {
+            let digit = self.digits[position] as u32;
+
+            if below >= 1 && digit < 9 {
+                self.digits[position] += 1;
+                self.fill_smallest(position + 1, below - 1);
+                self.length = usize::max(self.length, MAX_DIGITS - position);
+                return true;
+            }
+
+            below += digit;
+        }
+
+        false
+    }
+
+    /// Fill `digits[from..]` with the smallest digits that add up to `left`: the nines go last.
+    fn fill_smallest(&mut self, from: usize, mut left: u32) {
+        for digit in self.digits[from..].iter_mut().rev() {
+            *digit = u32::min(9, left) as u8;
+            left -= *digit as u32;
+        }
+    }
+
+    /// The lowest digit that can grow without breaking the order does,
+    /// and the digits after it get the smallest non-increasing digits with the sum that is left.
+    fn advance_multiset(&mut self) -> bool {
+        let mut below = 0;
+
+        for position in (0..self.length).rev() {
+            let digit = self.digits[position] as u32;
+            let highest = match position {
+                0 => 9,
+                _ => self.digits[position - 1] as u32,
+            };
+            let after = (self.length - position - 1) as u32;
+
+            if digit < highest && below >= 1 && below - 1 <= (digit + 1) * after
+            {
+                self.digits[position] += 1;
+                self.fill_non_increasing(position + 1, below - 1);
+                return true;
+            }
+
+            below += digit;
+        }
+
+        self.grow_multiset()
+    }
+
+    /// Move to the shortest length longer than the current one that can hold the sum.
+    fn grow_multiset(&mut self) -> bool {
+        let Some(length) = (self.length + 1..=MAX_DIGITS)
+            .find(|length| self.sum <= 9 * *length as u32)
+        else {
+            return false;
+        };
+
+        self.length = length;
+        self.fill_non_increasing(0, self.sum);
+
+        true
+    }
+
+    /// Fill `digits[from..length]` with the smallest non-increasing digits that add up to `left`,
+    /// none of them greater than the digit before.
+    ///
+    /// Every digit is spread as evenly as possible: the first one is the smallest, that still lets
+    /// the rest fit under it.
+    fn fill_non_increasing(&mut self, from: usize, mut left: u32) {
+        for position in from..self.length {
+            let positions = (self.length - position) as u32;
+            let digit = left.div_ceil(positions);
+
+            self.digits[position] = digit as u8;
+            left -= digit;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, num::NonZeroU8};
+
+    use crate::{DigitSum, integer, traits::SumSequencer};
+
+    use super::{Composition, DigitVectors};
+
+    fn digits(value: u64) -> Vec<u8> {
+        value.to_string().bytes().map(|byte| byte - b'0').collect()
+    }
+
+    #[test]
+    fn test_positional() {
+        for sum in (1..=50).filter_map(NonZeroU8::new) {
+            let mut vectors = DigitVectors::new(sum, Composition::Positional);
+
+            for value in integer::WithDigitSum(sum)
+                .get_ints(3000)
+                .take_while(|value| *value < 10_000_000)
+            {
+                assert_eq!(vectors.next(), Some(digits(value).as_slice()));
+            }
+        }
+
+        let mut vectors = DigitVectors::new(
+            NonZeroU8::new(1).unwrap(),
+            Composition::Positional,
+        );
+        let mut count = 0;
+
+        while let Some(digits) = vectors.next() {
+            count += 1;
+            assert_eq!(digits.len(), count);
+        }
+
+        assert_eq!(count, 20);
+        assert_eq!(vectors.next(), None);
+    }
+
+    #[test]
+    fn test_fixed_width() {
+        let sum = NonZeroU8::new(13).unwrap();
+
+        let fixed = DigitVectors::new(sum, Composition::Positional)
+            .fixed_width::<6>()
+            .collect::<Vec<_>>();
+
+        let expected = (0..1_000_000u64)
+            .filter(|value| value.digits_sum() == 13)
+            .map(|value| {
+                let mut padded = [0; 6];
+                let digits = digits(value);
+                padded[6 - digits.len()..].copy_from_slice(&digits);
+                padded
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(fixed, expected);
+    }
+
+    #[test]
+    fn test_multiset() {
+        for sum in (1..=40).filter_map(NonZeroU8::new) {
+            let mut seen = HashSet::new();
+            let mut expected = Vec::new();
+
+            for value in 1..100_000u64 {
+                let mut digits = digits(value);
+                digits.sort_by(|left, right| right.cmp(left));
+
+                if value.digits_sum() == sum.get() as u64
+                    && seen.insert(digits.clone())
+                {
+                    expected.push(digits);
+                }
+            }
+
+            expected.sort_by_key(|digits| (digits.len(), digits.clone()));
+
+            let mut vectors = DigitVectors::new(sum, Composition::Multiset);
+            let mut multisets = Vec::new();
+
+            while let Some(digits) =
+                vectors.next().filter(|digits| digits.len() <= 5)
+            {
+                multisets.push(digits.to_vec());
+            }
+
+            assert_eq!(multisets, expected, "sum {sum}");
+        }
+
+        let mut vectors = DigitVectors::new(
+            NonZeroU8::new(180).unwrap(),
+            Composition::Multiset,
+        );
+        assert_eq!(vectors.next(), Some([9; 20].as_slice()));
+        assert_eq!(vectors.next(), None);
+
+        let mut vectors = DigitVectors::new(
+            NonZeroU8::new(181).unwrap(),
+            Composition::Positional,
+        );
+        assert_eq!(vectors.next(), None);
+    }
+}
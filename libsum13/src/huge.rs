@@ -0,0 +1,246 @@
+//! Here are all the sequences of values that don't fit into u64: powers, factorials and Fibonacci numbers
+
+mod big_uint;
+
+pub use big_uint::BigUint;
+
+/// A sequence `f(0), f(1), f(2), ...` of huge values, each one computed from the previous ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HugeSequence {
+    /// `base^n`
+    Powers(u32),
+    /// `n!`
+    Factorials,
+    /// `F(n)`, starting from `F(0) = 0` and `F(1) = 1`
+    Fibonacci,
+}
+
+impl HugeSequence {
+    pub fn values(self) -> impl Iterator<Item = BigUint> + use<> {
+        let mut walker = Walker::new(self);
+
+        std::iter::from_fn(move || {
+            let value = walker.current().clone();
+            walker.advance();
+
+            Some(value)
+        })
+    }
+
+    /// Digits sums of `f(0), f(1), f(2), ...`
+    pub fn digits_sums(self) -> impl Iterator<Item = u64> + use<> {
+        let mut walker = Walker::new(self);
+
+        std::iter::from_fn(move || {
+            let sum = walker.current().digits_sum();
+            walker.advance();
+
+            Some(sum)
+        })
+    }
+
+    /// All the `n` in range `0..end` such that the digits sum of `f(n)` is `sum`.
+    ///
+    /// The digits sum is congruent to the value modulo 9, and the value modulo 9 is tracked on its own.
+    /// So the digits are only summed for the values that can have the right digits sum.
+    pub fn with_digits_sum(
+        self,
+        sum: u64,
+        end: u64,
+    ) -> impl Iterator<Item = u64> + use<> {
+        let target = (sum % 9) as u32;
+        let mut walker = Walker::new(self);
+
+        (0..end).filter(move |_| {
+            let found = walker.residue == target
+                && 9 * walker.current().digits_count() >= sum
+                && walker.current().digits_sum() == sum;
+            walker.advance();
+
+            found
+        })
+    }
+}
+
+/// The current value of the sequence along with its residue modulo 9.
+struct Walker {
+    sequence: HugeSequence,
+    n: u64,
+    value: BigUint,
+    /// `F(n + 1)`, only used by the Fibonacci numbers
+    next: BigUint,
+    residue: u32,
+    next_residue: u32,
+}
+
+impl Walker {
+    fn new(sequence: HugeSequence) -> Self {
+        let (value, next) = match sequence {
+            HugeSequence::Powers(_) | HugeSequence::Factorials => {
+                (BigUint::one(), BigUint::zero())
+            }
+            HugeSequence::Fibonacci => (BigUint::zero(), BigUint::one()),
+        };
+        let (residue, next_residue) = match sequence {
+            HugeSequence::Fibonacci => (0, 1),
+            _ => (1, 0),
+        };
+
+        Self {
+            sequence,
+            n: 0,
+            value,
+            next,
+            residue,
+            next_residue,
+        }
+    }
+
+    fn current(&self) -> &BigUint {
+        &self.value
+    }
+
+    fn advance(&mut self) {
+        self.n += 1;
+
+        match self.sequence {
+            HugeSequence::Powers(base) => {
+                self.value.mul_small(base);
+                self.residue = self.residue * (base % 9) % 9;
+            }
+            HugeSequence::Factorials => {
+                let factor =
+                    u32::try_from(self.n).expect("Factorial is too far");
+
+                self.value.mul_small(factor);
+                self.residue = self.residue * (factor % 9) % 9;
+            }
+            HugeSequence::Fibonacci => {
+                self.value.add(&self.next);
+                std::mem::swap(&mut self.value, &mut self.next);
+
+                let residue = (self.residue + self.next_residue) % 9;
+                self.residue = self.next_residue;
+                self.next_residue = residue;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::DigitSum;
+
+    use super::{BigUint, HugeSequence};
+
+    fn digits_sum(value: u128) -> u64 {
+        value
+            .to_string()
+            .bytes()
+            .map(|byte| (byte - b'0') as u64)
+            .sum()
+    }
+
+    #[test]
+    fn test_big_uint() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::zero().digits_count(), 1);
+        assert_eq!(BigUint::from(u64::MAX).to_string(), u64::MAX.to_string());
+        assert_eq!(BigUint::from(u64::MAX).digits_sum(), u64::MAX.digits_sum());
+
+        let mut value = BigUint::from(999_999_999_999);
+        value.add(&BigUint::one());
+        assert_eq!(value.to_string(), "1000000000000");
+        assert_eq!(value.digits_count(), 13);
+
+        value.mul_small(u32::MAX);
+        assert_eq!(
+            value.to_string(),
+            (1_000_000_000_000u128 * u32::MAX as u128).to_string()
+        );
+
+        value.mul_small(0);
+        assert!(value.is_zero());
+    }
+
+    #[test]
+    fn test_small_values() {
+        for base in [0, 1, 2, 3, 7, 10, 13] {
+            let expected = (0..30).map(|n| (base as u128).pow(n));
+
+            for (value, expected) in
+                HugeSequence::Powers(base).values().zip(expected)
+            {
+                assert_eq!(value.to_string(), expected.to_string());
+            }
+        }
+
+        let factorials = (1..=34u128).scan(1, |acc, n| {
+            let value = *acc;
+            *acc *= n;
+            Some(value)
+        });
+
+        for (value, expected) in
+            HugeSequence::Factorials.values().zip(factorials)
+        {
+            assert_eq!(value.to_string(), expected.to_string());
+        }
+
+        let fibonacci = (0..180).scan((0u128, 1u128), |(a, b), _| {
+            let value = *a;
+            (*a, *b) = (*b, *a + *b);
+            Some(value)
+        });
+
+        for (sum, expected) in
+            HugeSequence::Fibonacci.digits_sums().zip(fibonacci)
+        {
+            assert_eq!(sum, digits_sum(expected));
+        }
+    }
+
+    #[test]
+    fn test_known_digits_sums() {
+        assert_eq!(HugeSequence::Powers(2).digits_sums().nth(1000), Some(1366));
+        assert_eq!(HugeSequence::Factorials.digits_sums().nth(100), Some(648));
+
+        let factorial = HugeSequence::Factorials.values().nth(1000).unwrap();
+        assert_eq!(factorial.digits_count(), 2568);
+        assert_eq!(factorial.digits_sum(), 10539);
+
+        let fibonacci = HugeSequence::Fibonacci.values().nth(1000).unwrap();
+        assert_eq!(fibonacci.digits_count(), 209);
+    }
+
+    #[test]
+    fn test_with_digits_sum() {
+        for sequence in [
+            HugeSequence::Powers(2),
+            HugeSequence::Powers(3),
+            HugeSequence::Factorials,
+            HugeSequence::Fibonacci,
+        ] {
+            let sums = sequence.digits_sums().take(500).collect::<Vec<_>>();
+
+            for sum in [1, 2, 7, 9, 27, 45, 1000] {
+                let expected = (0..sums.len() as u64)
+                    .filter(|n| sums[*n as usize] == sum)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    sequence.with_digits_sum(sum, 500).collect::<Vec<_>>(),
+                    expected,
+                    "{sequence:?}, sum {sum}"
+                );
+            }
+        }
+
+        assert_eq!(
+            HugeSequence::Powers(10)
+                .with_digits_sum(1, 5)
+                .collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+}
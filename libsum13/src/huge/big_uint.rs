@@ -0,0 +1,145 @@
+use std::fmt;
+
+use crate::DigitSum;
+
+/// Every limb holds 9 decimal digits, so the digits sum can be read limb by limb.
+const BASE: u64 = 1_000_000_000;
+
+/// `DIGITS_SUMS[i]` is the digits sum of `i`: a limb is split into three of these.
+static DIGITS_SUMS: [u8; 10_000] = {
+    let mut table = [0u8; 10_000];
+
+    let mut i = 1;
+    while i < 10_000 {
+        table[i] = table[i / 10] + (i % 10) as u8;
+        i += 1;
+    }
+
+    table
+};
+
+/// Unsigned integer of any size, stored in base 10^9.
+///
+/// Only the operations the huge sequences need are here: multiplying by a small number and adding.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BigUint {
+    /// Least significant first, without the trailing zero limbs. Zero has no limbs at all.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    pub fn mul_small(&mut self, factor: u32) {
+        if factor == 0 {
+            self.limbs.clear();
+            return;
+        }
+
+        let mut carry = 0;
+
+        for limb in &mut self.limbs {
+            let product = *limb as u64 * factor as u64 + carry;
+            *limb = (product % BASE) as u32;
+            carry = product / BASE;
+        }
+
+        while carry != 0 {
+            self.limbs.push((carry % BASE) as u32);
+            carry /= BASE;
+        }
+    }
+
+    pub fn add(&mut self, other: &BigUint) {
+        if self.limbs.len() < other.limbs.len() {
+            self.limbs.resize(other.limbs.len(), 0);
+        }
+
+        let mut carry = 0;
+
+        for (i, limb) in self.limbs.iter_mut().enumerate() {
+            let sum = *limb as u64
+                + other.limbs.get(i).copied().unwrap_or(0) as u64
+                + carry;
+            *limb = (sum % BASE) as u32;
+            carry = sum / BASE;
+
+            if carry == 0 && i >= other.limbs.len() {
+                break;
+            }
+        }
+
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+    }
+
+    pub fn digits_sum(&self) -> u64 {
+        self.limbs
+            .iter()
+            .map(|limb| {
+                let limb = *limb as usize;
+
+                DIGITS_SUMS[limb % 10_000] as u64
+                    + DIGITS_SUMS[limb / 10_000 % 10_000] as u64
+                    + DIGITS_SUMS[limb / 100_000_000] as u64
+            })
+            .sum()
+    }
+
+    /// Number of decimal digits. Zero has one digit.
+    pub fn digits_count(&self) -> u64 {
+        match self.limbs.last() {
+            None => 1,
+            Some(last) => {
+                9 * (self.limbs.len() as u64 - 1)
+                    + last.to_string().len() as u64
+            }
+        }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(mut value: u64) -> Self {
+        let mut limbs = Vec::new();
+
+        while value != 0 {
+            limbs.push((value % BASE) as u32);
+            value /= BASE;
+        }
+
+        Self { limbs }
+    }
+}
+
+impl DigitSum for BigUint {
+    fn digits_sum(&self) -> u64 {
+        BigUint::digits_sum(self)
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((last, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+
+        write!(f, "{last}")?;
+
+        for limb in rest.iter().rev() {
+            write!(f, "{limb:09}")?;
+        }
+
+        Ok(())
+    }
+}
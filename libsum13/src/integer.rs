@@ -2,29 +2,41 @@
 
 mod advanced;
 mod bounded;
+mod counter;
+mod descending;
 mod dynamic;
+#[cfg(feature = "parallel")]
 mod fully_par;
 mod future_looking;
 mod sequential;
+mod signed;
 mod statique;
 #[cfg(feature = "unstable_deprecated")]
 mod naive_par;
 
-use std::num::NonZeroU8;
+use core::num::NonZeroU8;
 
 pub use advanced::WithDigitSumAdvanced;
 pub use bounded::IntsWithDigitSumInBounds;
+pub use counter::DigitSumCounter;
+pub use descending::WithDigitSumDescending;
 pub use dynamic::WithDigitSum;
+#[cfg(feature = "parallel")]
 pub use fully_par::FullyPar;
 pub use future_looking::FutureLooking;
 pub use sequential::SlowSequential;
-pub use statique::WithDigitSum13;
+pub use signed::SignedWithDigitSum;
+pub use statique::{WithDigitSum13, WithDigitSumConst};
 #[cfg(feature = "unstable_deprecated")]
 pub use naive_par::NaivePar;
 
-use crate::DigitSum;
+use crate::{
+    DigitSum,
+    combinatorics::{MAX_DIGITS, end_digits},
+};
 
-fn get_initial(sum: NonZeroU8) -> u64 {
+/// The smallest number which digits sum up to `sum`, the first one of `WithDigitSum`.
+pub const fn get_initial(sum: NonZeroU8) -> u64 {
     let mut sum_clone = sum.get();
     let mut first = 0u64;
     let mut i = 1;
@@ -72,45 +84,28 @@ fn count_addition(sum: NonZeroU8, value: u64) -> u64 {
     addition
 }
 
+#[cfg(test)]
 fn count_iterations(sum: NonZeroU8, start: u64, end: u64) -> u64 {
     let initial = get_initial(sum);
 
     let start_hundred = start / 100;
     let end_hundred = end / 100;
 
-    let mut assumed = start_hundred.digits_sum();
-
-    let full_hundreds_iters = (start_hundred..end_hundred)
-        .map(|i| {
-            let result = 'inner: {
-                let digit_sum = assumed;
-
-                if digit_sum > sum.get() as u64 {
-                    break 'inner 0;
-                }
-
-                let left = sum.get() as u64 - digit_sum;
-
-                let mut result = left + 1;
-
-                if initial <= 100
-                    && let right = digit_sum + (100u64 - initial) / 9
-                    && right < result
-                {
-                    result = right + 1;
-                }
+    let full_hundreds_iters = DigitSumCounter::new(start_hundred..end_hundred)
+        .map(|(_, digit_sum)| {
+            if digit_sum > sum.get() as u64 {
+                return 0;
+            }
 
-                result
-            };
+            let left = sum.get() as u64 - digit_sum;
 
-            assumed += 1;
+            let mut result = left + 1;
 
+            if initial <= 100
+                && let right = digit_sum + (100u64 - initial) / 9
+                && right < result
             {
-                let mut elem = i;
-                while elem % 10 == 9 {
-                    assumed -= 9;
-                    elem /= 10;
-                }
+                result = right + 1;
             }
 
             result
@@ -132,13 +127,108 @@ fn count_iterations(sum: NonZeroU8, start: u64, end: u64) -> u64 {
     full_hundreds_iters + remainder
 }
 
+/// `end_digits` go from the most significant one, the walks here go from the lowest one.
+fn lowest_first(value: u64) -> [u32; MAX_DIGITS] {
+    let mut digits = end_digits(value);
+    digits.reverse();
+
+    digits
+}
+
+/// The digits above `position` stay, the one at `position` becomes `digit`
+/// and the `left` sum is spread over the lower ones: the nines go to the top if `largest`.
+fn with_lower_digits(
+    digits: &[u32; MAX_DIGITS],
+    position: usize,
+    digit: u64,
+    mut left: u64,
+    largest: bool,
+) -> Option<u64> {
+    let mut result = digits[position + 1..]
+        .iter()
+        .rev()
+        .fold(0u128, |acc, digit| acc * 10 + *digit as u128);
+    result = result * 10 + digit as u128;
+
+    for lower in (0..position).rev() {
+        let filled = if largest {
+            u64::min(9, left)
+        } else {
+            left.saturating_sub(9 * lower as u64)
+        };
+
+        result = result * 10 + filled as u128;
+        left -= filled;
+    }
+
+    u64::try_from(result).ok()
+}
+
+/// The smallest number greater than `value` which digits sum up to `sum`.
+///
+/// The lowest digit that can grow does, and the sum that is left goes to the digits below it.
+pub(crate) fn next_with_digit_sum(value: u64, sum: u64) -> Option<u64> {
+    let digits = lowest_first(value);
+    let mut above = value.digits_sum();
+
+    for position in 0..MAX_DIGITS {
+        above -= digits[position] as u64;
+
+        let digit = (digits[position] as u64 + 1..=9).find(|digit| {
+            (above + digit..=above + digit + 9 * position as u64).contains(&sum)
+        });
+
+        if let Some(digit) = digit {
+            return with_lower_digits(
+                &digits,
+                position,
+                digit,
+                sum - above - digit,
+                false,
+            );
+        }
+    }
+
+    None
+}
+
+/// The greatest number less than `value` which digits sum up to `sum`.
+///
+/// The mirror of `next_with_digit_sum`: the lowest digit that can shrink does.
+pub(crate) fn previous_with_digit_sum(value: u64, sum: u64) -> Option<u64> {
+    let digits = lowest_first(value);
+    let mut above = value.digits_sum();
+
+    for position in 0..MAX_DIGITS {
+        above -= digits[position] as u64;
+
+        let digit = (0..digits[position] as u64).rev().find(|digit| {
+            (above + digit..=above + digit + 9 * position as u64).contains(&sum)
+        });
+
+        if let Some(digit) = digit {
+            return with_lower_digits(
+                &digits,
+                position,
+                digit,
+                sum - above - digit,
+                true,
+            );
+        }
+    }
+
+    None
+}
+
+#[cfg(feature = "parallel")]
 pub(crate) fn count_iter_end(sum: NonZeroU8, iterations: u32) -> u64 {
     // TODO: This must be optimizable. It is now the slowest part of the FullyPar realization.
     let mut iterations = iterations as u64;
     let mut i = 0;
     let initial = get_initial(sum);
     let sum_u64 = sum.get() as u64;
-    let mut assumed = 0;
+    // Every hundred is counted by the digits sum of the one after it
+    let mut hundreds = DigitSumCounter::new(1..);
 
     loop {
         if iterations == 0 {
@@ -146,17 +236,7 @@ pub(crate) fn count_iter_end(sum: NonZeroU8, iterations: u32) -> u64 {
         }
 
         iterations = iterations.saturating_sub('iter_count: {
-            assumed += 1;
-
-            {
-                let mut elem = i;
-                while elem % 10 == 9 {
-                    assumed -= 9;
-                    elem /= 10;
-                }
-            }
-
-            let digit_sum = assumed;
+            let (_, digit_sum) = hundreds.next().expect("Hundreds never end");
 
             if digit_sum > sum_u64 {
                 break 'iter_count 0;
@@ -189,7 +269,12 @@ mod tests {
 
     use crate::{DigitSum, integer::count_iterations, traits::SumSequencer};
 
-    use super::{IntsWithDigitSumInBounds, count_addition, get_initial};
+    use super::{
+        DigitSumCounter, IntsWithDigitSumInBounds, SignedWithDigitSum,
+        WithDigitSum, WithDigitSum13, WithDigitSumConst,
+        WithDigitSumDescending, count_addition, get_initial,
+        next_with_digit_sum, previous_with_digit_sum,
+    };
 
     #[test]
     fn test_initial() {
@@ -357,4 +442,202 @@ mod tests {
             panic!()
         }
     }
+
+    #[test]
+    fn test_next_and_previous() {
+        for sum in 1..=40 {
+            let expected = (0..100_000u64)
+                .filter(|value| value.digits_sum() == sum)
+                .collect::<Vec<_>>();
+
+            for value in (0..100_000u64).step_by(37) {
+                let next = expected.iter().find(|elem| **elem > value);
+                let previous = expected.iter().rev().find(|elem| **elem < value);
+
+                if let Some(next) = next {
+                    assert_eq!(next_with_digit_sum(value, sum), Some(*next));
+                }
+                assert_eq!(
+                    previous_with_digit_sum(value, sum),
+                    previous.copied(),
+                    "sum {sum}, value {value}"
+                );
+            }
+        }
+
+        assert_eq!(next_with_digit_sum(u64::MAX, 1), None);
+        assert_eq!(
+            next_with_digit_sum(10_000_000_000_000_000_000, 1),
+            None
+        );
+        assert_eq!(
+            previous_with_digit_sum(u64::MAX, 1),
+            Some(10_000_000_000_000_000_000)
+        );
+        assert_eq!(
+            previous_with_digit_sum(u64::MAX, 171),
+            Some(9_999_999_999_999_999_999)
+        );
+    }
+
+    #[test]
+    fn test_descending() {
+        let sum = NonZeroU8::new(13).unwrap();
+        let end = 123_456;
+
+        let expected = (0..end)
+            .rev()
+            .filter(|value| value.digits_sum() == 13)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            WithDigitSumDescending { end, sum }
+                .get_ints()
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_signed() {
+        for sum in (1..=30).filter_map(NonZeroU8::new) {
+            for (start, end) in [
+                (-100_000, 100_000),
+                (-54_321, -123),
+                (-1, 1),
+                (0, 12_345),
+                (777, 99_999),
+                (-1000, 0),
+                (5, -5),
+            ] {
+                let expected = (start..end)
+                    .filter(|value| value.digits_sum() == sum.get() as u64)
+                    .collect::<Vec<_>>();
+
+                assert_eq!(
+                    SignedWithDigitSum { start, end, sum }
+                        .get_ints()
+                        .collect::<Vec<_>>(),
+                    expected,
+                    "sum {sum}, {start}..{end}"
+                );
+            }
+        }
+
+        let extremes = SignedWithDigitSum {
+            start: i64::MIN,
+            end: i64::MAX,
+            sum: NonZeroU8::new(1).unwrap(),
+        }
+        .get_ints()
+        .collect::<Vec<_>>();
+
+        assert_eq!(extremes.len(), 2 * 19);
+        assert_eq!(extremes.first(), Some(&-1_000_000_000_000_000_000));
+        assert_eq!(extremes.last(), Some(&1_000_000_000_000_000_000));
+    }
+
+    #[test]
+    fn test_digit_sum_counter() {
+        let with_sums = |values: &mut dyn Iterator<Item = u64>| {
+            values
+                .map(|value| (value, value.digits_sum()))
+                .collect::<Vec<_>>()
+        };
+
+        for (start, end) in [(0, 0), (0, 1), (0, 12_345), (98_765, 123_456)] {
+            assert_eq!(
+                DigitSumCounter::new(start..end).collect::<Vec<_>>(),
+                with_sums(&mut (start..end))
+            );
+
+            for power in 1..=3 {
+                assert_eq!(
+                    DigitSumCounter::new(start..end)
+                        .step_by_power(power)
+                        .collect::<Vec<_>>(),
+                    with_sums(&mut (start..end).step_by(10usize.pow(power)))
+                );
+            }
+        }
+
+        let mut counter = DigitSumCounter::new(..1_000_000);
+        counter.seek(999_990);
+        assert_eq!(
+            counter.collect::<Vec<_>>(),
+            with_sums(&mut (999_990..1_000_000))
+        );
+
+        let last = u64::MAX - 3;
+        assert_eq!(
+            DigitSumCounter::new(last..).collect::<Vec<_>>(),
+            with_sums(&mut (last..=u64::MAX))
+        );
+        assert_eq!(
+            DigitSumCounter::new(9_999_999_999_999_999_990..)
+                .step_by_power(1)
+                .nth(1),
+            Some((10_000_000_000_000_000_000, 1))
+        );
+        assert_eq!(DigitSumCounter::new(..0).next(), None);
+    }
+
+    // The table of the greatest sum holds the first terms of all the smaller ones
+    const _: () = {
+        let mut sum = 1;
+
+        while sum <= 50 {
+            let initial = match NonZeroU8::new(sum) {
+                Some(sum) => get_initial(sum),
+                None => unreachable!(),
+            };
+
+            assert!(
+                WithDigitSumConst::<50>::ADDITIONS[sum as usize] == initial
+            );
+            sum += 1;
+        }
+
+        assert!(WithDigitSumConst::<50>::ADDITIONS[0] == 0);
+        assert!(WithDigitSumConst::<50>::INITIAL == 599_999);
+    };
+    const _: () = assert!(WithDigitSumConst::<13>::INITIAL == 49);
+    const _: () = assert!(WithDigitSumConst::<1>::INITIAL == 1);
+    const _: () =
+        assert!(WithDigitSumConst::<171>::INITIAL == 9_999_999_999_999_999_999);
+
+    #[test]
+    fn test_const_against_dynamic() {
+        macro_rules! assert_agree {
+            ($($sum:literal)*) => {$(
+                // The sparse sums take too long for the dynamic sequencer
+                let iterations = if $sum < 10 { 6 } else { 3000 };
+
+                assert_eq!(
+                    WithDigitSumConst::<$sum>
+                        .get_ints(iterations)
+                        .collect::<Vec<_>>(),
+                    WithDigitSum::new($sum)
+                        .get_ints(iterations)
+                        .collect::<Vec<_>>(),
+                    "sum {}",
+                    $sum
+                );
+            )*};
+        }
+
+        assert_agree!(
+            1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25
+            26 27 28 29 30 31 32 33 34 35 36 37 38 39 40 41 42 43 44 45 46 47
+            48 49 50
+        );
+
+        assert!(
+            WithDigitSumConst::<13>
+                .get_ints(100_000)
+                .eq(WithDigitSum13.get_ints(100_000))
+        );
+        assert_eq!(WithDigitSumConst::<13>.get_ints(0).count(), 0);
+        assert_eq!(WithDigitSumConst::<171>.get_ints(1).count(), 1);
+    }
 }
@@ -1,8 +1,8 @@
-use std::num::NonZeroU8;
+use core::num::NonZeroU8;
 
 use crate::{either_iterator::EitherIterator, DigitSum};
 
-use super::count_addition;
+use super::{DigitSumCounter, count_addition};
 
 #[derive(Debug)]
 pub struct IntsWithDigitSumInBounds {
@@ -23,25 +23,12 @@ impl IntsWithDigitSumInBounds {
             if start.digits_sum() == sum {
                 start
             } else {
-                let mut start_hundred = start / 100;
-
-                let mut assumed = start_hundred.digits_sum();
-
-                while (assumed > sum || sum - assumed >= 100)
-                    && start_hundred * 100 <= end
-                {
-                    assumed += 1;
-
-                    {
-                        let mut elem = start_hundred;
-                        while elem % 10 == 9 {
-                            assumed -= 9;
-                            elem /= 10;
-                        }
-                    }
-
-                    start_hundred += 1;
-                }
+                let (start_hundred, _) = DigitSumCounter::new(start / 100..)
+                    .find(|(hundred, assumed)| {
+                        (*assumed <= sum && sum - assumed < 100)
+                            || hundred * 100 > end
+                    })
+                    .expect("Some hundred fits the sum");
 
                 let start_hundred = start_hundred * 100;
 
@@ -57,25 +44,15 @@ impl IntsWithDigitSumInBounds {
             *acc = if next.digits_sum() == sum {
                 next
             } else {
-                let mut next_hundred = *acc / 100 + 1;
-
-                let mut assumed = next_hundred.digits_sum();
-
-                while assumed > sum || sum - assumed >= 100 {
-                    assumed += 1;
-
-                    {
-                        let mut elem = next_hundred;
-                        while elem % 10 == 9 {
-                            assumed -= 9;
-                            elem /= 10;
-                        }
-                    }
-
-                    next_hundred += 1;
-                    if next_hundred * 100 > end {
-                        return None;
-                    }
+                let (next_hundred, _) = DigitSumCounter::new(*acc / 100 + 1..)
+                    .find(|(hundred, assumed)| {
+                        (*assumed <= sum && sum - assumed < 100)
+                            || hundred * 100 > end
+                    })
+                    .expect("Some hundred fits the sum");
+
+                if next_hundred * 100 > end {
+                    return None;
                 }
 
                 let next_hundred = next_hundred * 100;
@@ -93,7 +70,7 @@ impl IntsWithDigitSumInBounds {
         });
 
         if initial.digits_sum() == sum && initial < end {
-            EitherIterator::Left(std::iter::once(initial).chain(inner_iter))
+            EitherIterator::Left(core::iter::once(initial).chain(inner_iter))
         } else {
             EitherIterator::Right(inner_iter)
         }
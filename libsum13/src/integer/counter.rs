@@ -0,0 +1,97 @@
+use core::ops::{Bound, RangeBounds};
+
+use crate::DigitSum;
+
+/// Every number of a range along with its digits sum, in amortized O(1) per number.
+///
+/// Only the digits sum of the start is calculated digit by digit. After that, adding one adds one
+/// to the digits sum, except that every trailing nine turns into a zero and takes its 9 away.
+/// Stepping by `10^k` is the same thing with the lowest `k` digits left as they are.
+#[derive(Debug, Clone)]
+pub struct DigitSumCounter {
+    value: u64,
+    digits_sum: u64,
+    /// The last value of the range, it's included
+    last: u64,
+    step: u64,
+    exhausted: bool,
+}
+
+impl DigitSumCounter {
+    pub fn new(range: impl RangeBounds<u64>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(start) => Some(*start),
+            Bound::Excluded(start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let last = match range.end_bound() {
+            Bound::Included(end) => Some(*end),
+            Bound::Excluded(end) => end.checked_sub(1),
+            Bound::Unbounded => Some(u64::MAX),
+        };
+
+        let (Some(start), Some(last)) = (start, last) else {
+            return Self {
+                value: 0,
+                digits_sum: 0,
+                last: 0,
+                step: 1,
+                exhausted: true,
+            };
+        };
+
+        Self {
+            value: start,
+            digits_sum: start.digits_sum(),
+            last,
+            step: 1,
+            exhausted: start > last,
+        }
+    }
+
+    /// Move to `10^power` at a time instead of one, starting from the current value.
+    pub fn step_by_power(mut self, power: u32) -> Self {
+        self.step = 10u64.checked_pow(power).expect("Step must fit into u64");
+        self
+    }
+
+    /// Continue from `value`: the rest of the range and the step stay the same.
+    pub fn seek(&mut self, value: u64) {
+        self.value = value;
+        self.digits_sum = value.digits_sum();
+        self.exhausted = value > self.last;
+    }
+}
+
+impl Iterator for DigitSumCounter {
+    type Item = (u64, u64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let current = (self.value, self.digits_sum);
+
+        match self.value.checked_add(self.step) {
+            Some(next) if next <= self.last => {
+                self.digits_sum += 1;
+
+                // Dividing on every step is slow, and it's mostly a step of one
+                let mut elem = match self.step {
+                    1 => self.value,
+                    step => self.value / step,
+                };
+                while elem % 10 == 9 {
+                    self.digits_sum -= 9;
+                    elem /= 10;
+                }
+
+                self.value = next;
+            }
+            _ => self.exhausted = true,
+        }
+
+        Some(current)
+    }
+}
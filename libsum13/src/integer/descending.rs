@@ -0,0 +1,21 @@
+use core::num::NonZeroU8;
+
+use super::previous_with_digit_sum;
+
+/// All the numbers below `end` which digits sum up to `sum`, from the greatest one down.
+#[derive(Debug)]
+pub struct WithDigitSumDescending {
+    pub end: u64,
+    pub sum: NonZeroU8,
+}
+
+impl WithDigitSumDescending {
+    pub fn get_ints(&self) -> impl Iterator<Item = u64> + use<> {
+        let sum = self.sum.get() as u64;
+
+        core::iter::successors(
+            previous_with_digit_sum(self.end, sum),
+            move |value| previous_with_digit_sum(*value, sum),
+        )
+    }
+}
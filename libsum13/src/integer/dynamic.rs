@@ -1,5 +1,5 @@
 use crate::{traits::{SumSequencer, SumSequencerMut}, DigitSum};
-use std::num::NonZeroU8;
+use core::num::NonZeroU8;
 
 use crate::{impl_mut_for_refmut, new_expect};
 
@@ -15,7 +15,7 @@ impl SumSequencer for WithDigitSum {
         let initial = get_initial(sum);
         let sum = sum.get() as u64;
 
-        std::iter::once(initial).chain((0..iterations - 1).scan(
+        core::iter::once(initial).chain((0..iterations - 1).scan(
             initial,
             move |acc, _| {
                 let next = *acc + 9;
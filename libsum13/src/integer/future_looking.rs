@@ -1,11 +1,11 @@
-use std::num::NonZeroU8;
+use core::num::NonZeroU8;
 
 use crate::{
     impl_mut_for_refmut, new_expect,
     traits::{SumSequencer, SumSequencerMut}, DigitSum,
 };
 
-use super::get_initial;
+use super::{DigitSumCounter, get_initial};
 
 pub struct FutureLooking(pub NonZeroU8);
 new_expect!(FutureLooking);
@@ -16,7 +16,7 @@ impl SumSequencer for FutureLooking {
         let initial = get_initial(self.0);
         let sum = self.0.get() as u64;
 
-        std::iter::once(initial).chain((0..iterations - 1).scan(
+        core::iter::once(initial).chain((0..iterations - 1).scan(
             initial,
             move |acc, _| {
                 let next = *acc + 9;
@@ -24,25 +24,14 @@ impl SumSequencer for FutureLooking {
                 *acc = if next.digits_sum() == sum {
                     next
                 } else {
-                    let mut next = *acc / 100 + 1;
-
-                    let mut assumed = next.digits_sum();
-
-                    while assumed > sum || sum - assumed >= 100 {
-                        assumed += 1;
-
-                        {
-                            let mut elem = next;
-                            while elem % 10 == 9 {
-                                assumed -= 9;
-                                elem /= 10;
-                            }
-                        }
-
-                        next += 1;
-                    }
-
-                    next *= 100;
+                    let (next, assumed) =
+                        DigitSumCounter::new(*acc / 100 + 1..)
+                            .find(|(_, assumed)| {
+                                *assumed <= sum && sum - assumed < 100
+                            })
+                            .expect("Some hundred fits the sum");
+
+                    let next = next * 100;
 
                     let mut remainder = sum - assumed;
                     let mut addition = 0;
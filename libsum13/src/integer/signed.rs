@@ -0,0 +1,57 @@
+use core::num::NonZeroU8;
+
+use crate::DigitSum;
+
+use super::{WithDigitSumDescending, next_with_digit_sum};
+
+/// All the numbers in range `start..end` which digits sum up to `sum`, in increasing order.
+///
+/// The digits sum of a negative number is the digits sum of its absolute value.
+/// So the negative part goes through the absolute values from the greatest one down,
+/// and the rest goes up as usual.
+#[derive(Debug)]
+pub struct SignedWithDigitSum {
+    pub start: i64,
+    pub end: i64,
+    pub sum: NonZeroU8,
+}
+
+impl SignedWithDigitSum {
+    pub fn get_ints(&self) -> impl Iterator<Item = i64> + use<> {
+        let sum = self.sum.get() as u64;
+
+        let negative = (self.start < 0).then(|| {
+            // The absolute values are in range `lowest + 1..=start.unsigned_abs()`
+            let lowest = self.end.min(0).unsigned_abs();
+
+            WithDigitSumDescending {
+                end: self.start.unsigned_abs() + 1,
+                sum: self.sum,
+            }
+            .get_ints()
+            .take_while(move |value| *value > lowest)
+            .map(|value| (value as i64).wrapping_neg())
+        });
+
+        let positive = (self.end > 0).then(|| {
+            let start = self.start.max(0) as u64;
+            let end = self.end as u64;
+            let first = if start.digits_sum() == sum {
+                Some(start)
+            } else {
+                next_with_digit_sum(start, sum)
+            };
+
+            core::iter::successors(first, move |value| {
+                next_with_digit_sum(*value, sum)
+            })
+            .take_while(move |value| *value < end)
+            .map(|value| value as i64)
+        });
+
+        negative
+            .into_iter()
+            .flatten()
+            .chain(positive.into_iter().flatten())
+    }
+}
@@ -1,15 +1,20 @@
+use core::num::NonZeroU8;
+
 use crate::{
     DigitSum,
+    combinatorics::MAX_SUM,
     impl_mut_for_refmut,
     traits::{SumSequencer, SumSequencerMut},
 };
 
+use super::get_initial;
+
 pub struct WithDigitSum13;
 impl_mut_for_refmut!(WithDigitSum13);
 
 impl SumSequencer for WithDigitSum13 {
     fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
-        std::iter::once(49).chain((0..iterations - 1).scan(49u64, |acc, _| {
+        core::iter::once(49).chain((0..iterations - 1).scan(49u64, |acc, _| {
             let next = *acc + 9;
 
             *acc = if next.digits_sum() == 13 {
@@ -46,3 +51,70 @@ impl SumSequencer for WithDigitSum13 {
         }))
     }
 }
+
+/// The same as `WithDigitSum`, but the digits sum is known at compile time,
+/// so is the first term and what gets added to every hundred.
+///
+/// The sum must be from 1 to 171: the first term of 172 doesn't fit into u64 already.
+pub struct WithDigitSumConst<const M: u8>;
+
+impl<const M: u8> WithDigitSumConst<M> {
+    pub const INITIAL: u64 = match NonZeroU8::new(M) {
+        Some(sum) => get_initial(sum),
+        None => panic!("Digits sum must be nonzero"),
+    };
+
+    /// `ADDITIONS[r]` is the smallest number which digits sum up to `r`, and zero for `r = 0`.
+    /// A hundred with the digits sum `s` needs `ADDITIONS[M - s]` on top of it.
+    pub(super) const ADDITIONS: [u64; MAX_SUM + 1] = {
+        let mut additions = [0; MAX_SUM + 1];
+        let mut left = 1;
+
+        while left <= M {
+            additions[left as usize] = match NonZeroU8::new(left) {
+                Some(left) => get_initial(left),
+                None => unreachable!(),
+            };
+            left += 1;
+        }
+
+        additions
+    };
+}
+
+impl<const M: u8> SumSequencer for WithDigitSumConst<M> {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<M> {
+        let initial = Self::INITIAL;
+        let sum = M as u64;
+
+        core::iter::once(initial)
+            .chain((1..iterations).scan(initial, move |acc, _| {
+                let next = *acc + 9;
+
+                *acc = if next.digits_sum() == sum {
+                    next
+                } else {
+                    let mut next = (*acc + 1).next_multiple_of(100);
+
+                    while next.digits_sum() > sum {
+                        next = (next + 1).next_multiple_of(100);
+                    }
+
+                    next + Self::ADDITIONS[(sum - next.digits_sum()) as usize]
+                };
+
+                Some(*acc)
+            }))
+            .take(iterations as usize)
+    }
+}
+
+#[allow(refining_impl_trait)]
+impl<const M: u8> SumSequencerMut for &mut WithDigitSumConst<M> {
+    fn get_ints(
+        &mut self,
+        iterations: u32,
+    ) -> impl Iterator<Item = u64> + use<M> {
+        SumSequencerMut::get_ints(*self, iterations)
+    }
+}
@@ -1,12 +1,36 @@
-mod combinatorics;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "std")]
+pub mod analysis;
+#[cfg(feature = "std")]
+pub mod bases;
+#[cfg(feature = "std")]
+pub mod calendar;
+pub mod combinatorics;
+pub mod composition;
+#[cfg(feature = "std")]
+pub mod huge;
 pub mod integer;
+#[cfg(feature = "std")]
+pub mod merge;
+#[cfg(feature = "std")]
+pub mod mixed_radix;
+#[cfg(feature = "std")]
+pub mod number_theory;
+#[cfg(feature = "std")]
+pub mod restricted;
+#[cfg(feature = "std")]
+pub mod sampling;
+#[cfg(feature = "std")]
 pub mod string;
 pub mod traits;
 mod either_iterator;
+#[cfg(feature = "std")]
 mod utils;
 mod macros;
 
-trait DigitSum {
+/// Sum of the digits in some numeral system.
+pub trait DigitSum {
     fn digits_sum(&self) -> u64;
 }
 
@@ -31,19 +55,29 @@ impl DigitSum for u64 {
     }
 }
 
+/// The digits sum of a negative number is the digits sum of its absolute value.
+impl DigitSum for i64 {
+    fn digits_sum(&self) -> u64 {
+        self.unsigned_abs().digits_sum()
+    }
+}
+
 pub fn add(left: u64, right: u64) -> u64 {
     left + right
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{collections::HashSet, num::NonZeroU8};
+    #[cfg(feature = "parallel")]
+    use std::collections::HashSet;
+    #[cfg(feature = "std")]
+    use std::num::NonZeroU8;
 
-    use crate::{
-        integer, string, traits::SumSequencerOnce,
-    };
+    #[cfg(feature = "std")]
+    use crate::{integer, string, traits::SumSequencerOnce};
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_int_variant() {
         let iterations = 10000;
         let strval = string::WithDigitSum13 {}.get_ints(iterations);
@@ -55,6 +89,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_int_general() {
         let spawn_thing = |number: NonZeroU8| {
             let iterations = 2 * number.get() as u32;
@@ -79,6 +114,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "std")]
     fn test_max_nonerroring_sum() {
         let mut should_panic = false;
 
@@ -129,6 +165,7 @@ mod tests {
                 should_panic = true;
             }
 
+            #[cfg(feature = "parallel")]
             if fails_check(
                 integer::FullyPar(sum).get_ints(iterations),
                 sum,
@@ -214,6 +251,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "parallel")]
     fn test_fully_par_with_zip() {
         let iterations = 100_000;
         let intval = integer::WithDigitSum::new(13).get_ints(iterations);
@@ -239,6 +277,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "parallel")]
     fn test_fully_par_with_hashsets() {
         let iterations = 100_000;
         let intval = integer::WithDigitSum::new(13)
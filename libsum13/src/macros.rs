@@ -6,7 +6,7 @@ macro_rules! new_expect {
         #[allow(dead_code)]
         impl $owner {
             pub fn new(
-                value: impl TryInto<NonZeroU8, Error: std::fmt::Debug>,
+                value: impl TryInto<NonZeroU8, Error: core::fmt::Debug>,
             ) -> Self {
                 Self(value.try_into().expect("Digits sum must be nonzero"))
             }
@@ -0,0 +1,272 @@
+//! Here are all the adapters that combine the sorted outputs of several sequencers
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::traits::SumSequencer;
+
+/// How the sources are combined.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeKind {
+    /// Everything that any of the sources yields
+    Union,
+    /// Only what all of the sources yield
+    Intersection,
+    /// What the first source yields, but none of the others do
+    Difference,
+}
+
+/// A value along with the sources that yielded it: bit `i` is set for the `i`-th source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Labeled {
+    pub value: u64,
+    pub sources: u64,
+}
+
+impl Labeled {
+    pub fn is_from(&self, source: usize) -> bool {
+        self.sources & (1 << source) != 0
+    }
+}
+
+/// K-way merge of strictly increasing sources, which is strictly increasing itself.
+///
+/// The heap holds the current head of every source, so every step is logarithmic in their number.
+/// The intersection of the sources that never meet again never ends, just like the sources do.
+pub struct SortedMerge<I> {
+    sources: Vec<I>,
+    heads: BinaryHeap<Reverse<(u64, usize)>>,
+    kind: MergeKind,
+    /// Some of the sources are over, so there is no need to look for the intersection anymore
+    exhausted: bool,
+}
+
+impl<I: Iterator<Item = u64>> SortedMerge<I> {
+    pub fn new(sources: impl IntoIterator<Item = I>, kind: MergeKind) -> Self {
+        let mut sources = sources.into_iter().collect::<Vec<_>>();
+
+        assert!(
+            (1..=64).contains(&sources.len()),
+            "There must be from 1 to 64 sources"
+        );
+
+        let mut heads = BinaryHeap::with_capacity(sources.len());
+        let mut exhausted = false;
+
+        for (index, source) in sources.iter_mut().enumerate() {
+            match source.next() {
+                Some(value) => heads.push(Reverse((value, index))),
+                None => exhausted = true,
+            }
+        }
+
+        Self {
+            sources,
+            heads,
+            kind,
+            exhausted,
+        }
+    }
+
+    fn all_sources(&self) -> u64 {
+        u64::MAX >> (64 - self.sources.len())
+    }
+
+    /// Take all the heads equal to the smallest one and move their sources forward.
+    fn pop_smallest(&mut self) -> Option<Labeled> {
+        let Reverse((value, _)) = *self.heads.peek()?;
+        let mut sources = 0;
+
+        while let Some(Reverse((head, index))) = self.heads.peek().copied()
+            && head == value
+        {
+            self.heads.pop();
+            sources |= 1 << index;
+
+            match self.sources[index].next() {
+                Some(next) => {
+                    debug_assert!(
+                        next > head,
+                        "Sources must be strictly increasing"
+                    );
+                    self.heads.push(Reverse((next, index)));
+                }
+                None => self.exhausted = true,
+            }
+        }
+
+        Some(Labeled { value, sources })
+    }
+}
+
+impl<I: Iterator<Item = u64>> Iterator for SortedMerge<I> {
+    type Item = Labeled;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.kind {
+                MergeKind::Intersection if self.exhausted => return None,
+                // Once the first source is over, nothing is left to yield
+                MergeKind::Difference
+                    if !self
+                        .heads
+                        .iter()
+                        .any(|Reverse((_, index))| *index == 0) =>
+                {
+                    return None;
+                }
+                _ => {}
+            }
+
+            let labeled = self.pop_smallest()?;
+
+            let found = match self.kind {
+                MergeKind::Union => true,
+                MergeKind::Intersection => {
+                    labeled.sources == self.all_sources()
+                }
+                MergeKind::Difference => labeled.sources == 1,
+            };
+
+            if found {
+                return Some(labeled);
+            }
+        }
+    }
+}
+
+/// Merge the outputs of the sequencers, each one limited to `iterations` numbers.
+pub fn merge<S: SumSequencer>(
+    sequencers: &[S],
+    iterations: u32,
+    kind: MergeKind,
+) -> SortedMerge<impl Iterator<Item = u64> + use<S>> {
+    SortedMerge::new(
+        sequencers
+            .iter()
+            .map(|sequencer| sequencer.get_ints(iterations)),
+        kind,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{
+        DigitSum, integer, restricted::Palindromes, traits::SumSequencer,
+    };
+
+    use super::{Labeled, MergeKind, SortedMerge, merge};
+
+    #[test]
+    fn test_merge_with_digit_sums() {
+        let limit = 100_000;
+        let sequencers = [
+            integer::WithDigitSum::new(13),
+            integer::WithDigitSum::new(31),
+            integer::WithDigitSum::new(4),
+        ];
+
+        let union = merge(&sequencers, u32::MAX, MergeKind::Union)
+            .take_while(|labeled| labeled.value < limit)
+            .collect::<Vec<_>>();
+
+        let expected = (0..limit)
+            .filter_map(|value| {
+                let sum = value.digits_sum();
+                let sources = match sum {
+                    13 => 1,
+                    31 => 2,
+                    4 => 4,
+                    _ => return None,
+                };
+
+                Some(Labeled { value, sources })
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(union, expected);
+
+        // Different digits sums never meet, but the finite sources end the intersection
+        assert_eq!(
+            merge(&sequencers, 1000, MergeKind::Intersection).next(),
+            None
+        );
+
+        let difference = merge(&sequencers[..2], 500, MergeKind::Difference)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            difference,
+            integer::WithDigitSum::new(13)
+                .get_ints(500)
+                .map(|value| Labeled { value, sources: 1 })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_merge_against_sets() {
+        let limit = 1_000_000;
+        let palindromes = Palindromes::new(13)
+            .get_ints(u32::MAX)
+            .take_while(|value| *value < limit)
+            .collect::<Vec<_>>();
+        let with_sum = integer::WithDigitSum::new(13)
+            .get_ints(u32::MAX)
+            .take_while(|value| *value < limit)
+            .collect::<Vec<_>>();
+        let tens = (0..limit).step_by(10).collect::<Vec<_>>();
+
+        let sets = [&palindromes, &with_sum, &tens]
+            .map(|values| values.iter().copied().collect::<BTreeSet<_>>());
+
+        let union = SortedMerge::new(
+            [&palindromes, &with_sum, &tens]
+                .map(|values| values.iter().copied()),
+            MergeKind::Union,
+        )
+        .collect::<Vec<_>>();
+        let expected = sets[0]
+            .union(&sets[1])
+            .copied()
+            .collect::<BTreeSet<_>>()
+            .union(&sets[2])
+            .copied()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            union
+                .iter()
+                .map(|labeled| labeled.value)
+                .collect::<Vec<_>>(),
+            expected
+        );
+
+        for labeled in &union {
+            for (source, set) in sets.iter().enumerate() {
+                assert_eq!(
+                    labeled.is_from(source),
+                    set.contains(&labeled.value)
+                );
+            }
+        }
+
+        let combine = |kind| {
+            SortedMerge::new(
+                [&with_sum, &tens].map(|values| values.iter().copied()),
+                kind,
+            )
+            .map(|labeled| labeled.value)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            combine(MergeKind::Intersection),
+            sets[1].intersection(&sets[2]).copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            combine(MergeKind::Difference),
+            sets[1].difference(&sets[2]).copied().collect::<Vec<_>>()
+        );
+    }
+}
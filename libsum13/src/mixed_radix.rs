@@ -0,0 +1,404 @@
+//! Here are all the sequencers that use a mixed-radix numeral system instead of the decimal one
+
+use std::num::NonZeroU8;
+
+use crate::{
+    DigitSum, impl_mut_for_refmut,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+/// A mixed-radix numeral system: every position has its own radix.
+///
+/// The radices go from the least significant position, like (minutes, hours) for `[60, 24]`.
+/// The most significant digit is unbounded, so the number of days in that example
+/// is the last digit, and every u64 has its representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MixedRadix {
+    radices: Vec<u64>,
+}
+
+impl MixedRadix {
+    /// The positions that can only hold zeros for u64 values are dropped.
+    pub fn new(radices: impl IntoIterator<Item = u64>) -> Self {
+        let mut weight = Some(1u64);
+
+        let radices = radices
+            .into_iter()
+            .inspect(|radix| assert!(*radix >= 2, "Radix must be at least 2"))
+            .take_while(|radix| {
+                let fits = weight.is_some();
+                weight = weight.and_then(|weight| weight.checked_mul(*radix));
+                fits
+            })
+            .collect();
+
+        Self { radices }
+    }
+
+    /// The ordinary numeral system with `positions` bounded digits in the given radix.
+    pub fn fixed(radix: u64, positions: usize) -> Self {
+        Self::new(std::iter::repeat_n(radix, positions))
+    }
+
+    /// Factorial number system: the radices are 2, 3, 4 and so on, so the weights are the factorials.
+    pub fn factorial(positions: usize) -> Self {
+        Self::new((2..).take(positions))
+    }
+
+    pub fn radices(&self) -> &[u64] {
+        &self.radices
+    }
+
+    pub fn number(&self, value: u64) -> MixedRadixNumber<'_> {
+        MixedRadixNumber { value, radix: self }
+    }
+
+    /// The value with the given digits, least significant first. `None` if it doesn't fit.
+    pub fn value(&self, digits: &[u64]) -> Option<u64> {
+        assert!(
+            digits.len() <= self.radices.len() + 1,
+            "Too many digits for the radices"
+        );
+
+        digits.iter().zip(&self.radices).for_each(|(digit, radix)| {
+            assert!(digit < radix, "Digit must be less than its radix")
+        });
+
+        digits
+            .iter()
+            .zip(self.weights())
+            .filter(|(digit, _)| **digit != 0)
+            .try_fold(0u64, |acc, (digit, weight)| {
+                acc.checked_add(digit.checked_mul(weight?)?)
+            })
+    }
+
+    /// Weights of all the positions including the unbounded one, or `None` for those that overflow.
+    fn weights(&self) -> impl Iterator<Item = Option<u64>> + '_ {
+        std::iter::once(Some(1)).chain(self.radices.iter().scan(
+            Some(1u64),
+            |weight, radix| {
+                *weight = weight.and_then(|weight| weight.checked_mul(*radix));
+                Some(*weight)
+            },
+        ))
+    }
+}
+
+/// A value written in a mixed-radix numeral system.
+#[derive(Debug, Clone, Copy)]
+pub struct MixedRadixNumber<'a> {
+    pub value: u64,
+    pub radix: &'a MixedRadix,
+}
+
+impl MixedRadixNumber<'_> {
+    /// The digits, least significant first, the unbounded one included.
+    pub fn digits(&self) -> impl Iterator<Item = u64> + '_ {
+        MixedDigitIter {
+            value: self.value,
+            radices: self.radix.radices.iter(),
+        }
+    }
+}
+
+impl DigitSum for MixedRadixNumber<'_> {
+    fn digits_sum(&self) -> u64 {
+        self.digits().sum()
+    }
+}
+
+/// The same as `DigitIter`, but every digit has its own radix.
+struct MixedDigitIter<'a> {
+    value: u64,
+    radices: std::slice::Iter<'a, u64>,
+}
+
+impl Iterator for MixedDigitIter<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.value == 0 {
+            return None;
+        }
+
+        match self.radices.next() {
+            Some(radix) => {
+                let digit = self.value % radix;
+                self.value /= radix;
+                Some(digit)
+            }
+            None => Some(std::mem::take(&mut self.value)),
+        }
+    }
+}
+
+/// Numbers which digits in the mixed-radix system sum up to `sum`, in increasing order.
+pub struct MixedRadixWithDigitSum {
+    pub radix: MixedRadix,
+    pub sum: NonZeroU8,
+}
+impl_mut_for_refmut!(MixedRadixWithDigitSum);
+
+impl SumSequencer for MixedRadixWithDigitSum {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let radix = self.radix.clone();
+        let mut digits = Some(Digits::first(&radix, self.sum.get() as u64));
+
+        std::iter::from_fn(move || {
+            let current = digits.as_mut()?;
+            // Once a number doesn't fit, none of the following ones fit either
+            let Some(value) = radix.value(&current.0) else {
+                digits = None;
+                return None;
+            };
+
+            if !current.advance(&radix) {
+                digits = None;
+            }
+
+            Some(value)
+        })
+        .take(iterations as usize)
+    }
+}
+
+impl MixedRadixWithDigitSum {
+    /// Count all the numbers in range `0..end` which digits sum up to `self.sum`.
+    pub fn count(&self, end: u64) -> u64 {
+        let sum = self.sum.get() as u64;
+        let radices = &self.radix.radices;
+        let ways = ways(radices, sum);
+
+        let end_digits = self.radix.number(end).digits().collect::<Vec<_>>();
+        let mut end_digits = end_digits.as_slice();
+        let mut left = sum;
+        let mut count = 0;
+
+        // The unbounded digit goes first: only the ones up to `sum` can be followed by anything
+        if end_digits.len() > radices.len() {
+            let (top, rest) = end_digits.split_last().expect("Not empty");
+
+            count += (0..u64::min(*top, left + 1))
+                .map(|digit| ways[radices.len()][(left - digit) as usize])
+                .sum::<u128>();
+
+            if *top > left {
+                return count as u64;
+            }
+
+            left -= top;
+            end_digits = rest;
+        }
+
+        for (position, end_digit) in end_digits.iter().enumerate().rev() {
+            count += (0..u64::min(*end_digit, left + 1))
+                .map(|digit| ways[position][(left - digit) as usize])
+                .sum::<u128>();
+
+            if *end_digit > left {
+                return count as u64;
+            }
+
+            left -= end_digit;
+        }
+
+        count as u64
+    }
+}
+
+/// `ways[k][s]` is the number of digit strings on the lowest `k` positions which sum up to `s`.
+fn ways(radices: &[u64], sum: u64) -> Vec<Vec<u128>> {
+    let mut ways = vec![vec![0u128; sum as usize + 1]];
+    ways[0][0] = 1;
+
+    for radix in radices {
+        let previous = ways.last().expect("Not empty");
+        let next = (0..=sum)
+            .map(|total| {
+                (0..=u64::min(radix - 1, total))
+                    .map(|digit| previous[(total - digit) as usize])
+                    .fold(0u128, u128::saturating_add)
+            })
+            .collect();
+
+        ways.push(next);
+    }
+
+    ways
+}
+
+/// Digits of the current number, least significant first, the unbounded one is the last.
+struct Digits(Vec<u64>);
+
+impl Digits {
+    fn first(radix: &MixedRadix, sum: u64) -> Self {
+        let mut digits = Digits(vec![0; radix.radices.len() + 1]);
+        digits.fill_smallest(radix, radix.radices.len(), sum);
+        digits
+    }
+
+    /// Fill the lowest `positions` with the smallest number that has the digits sum `left`,
+    /// adding whatever doesn't fit to the next position.
+    fn fill_smallest(
+        &mut self,
+        radix: &MixedRadix,
+        positions: usize,
+        mut left: u64,
+    ) {
+        for (digit, radix) in
+            self.0.iter_mut().zip(&radix.radices).take(positions)
+        {
+            *digit = u64::min(radix - 1, left);
+            left -= *digit;
+        }
+
+        self.0[positions] += left;
+    }
+
+    /// Move to the next number with the same digits sum:
+    /// the lowest digit that can grow takes one from the digits below it,
+    /// and they get rearranged into the smallest number.
+    ///
+    /// Returns false once the whole sum is in the unbounded digit: there is no next number then.
+    fn advance(&mut self, radix: &MixedRadix) -> bool {
+        let mut below = 0;
+
+        for position in 0..self.0.len() {
+            let can_grow = radix
+                .radices
+                .get(position)
+                .is_none_or(|radix| self.0[position] + 1 < *radix);
+
+            if below >= 1 && can_grow {
+                // The digits below held `below`, so one less surely fits into them
+                self.0[position] += 1;
+                self.fill_smallest(radix, position, below - 1);
+                return true;
+            }
+
+            below += self.0[position];
+        }
+
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU8;
+
+    use crate::{DigitSum, integer, traits::SumSequencer};
+
+    use super::{MixedRadix, MixedRadixWithDigitSum};
+
+    #[test]
+    fn test_digits() {
+        let clock = MixedRadix::new([60, 24]);
+        let value = 3 * 24 * 60 + 5 * 60 + 7;
+
+        assert_eq!(
+            clock.number(value).digits().collect::<Vec<_>>(),
+            vec![7, 5, 3]
+        );
+        assert_eq!(clock.number(value).digits_sum(), 15);
+        assert_eq!(clock.value(&[7, 5, 3]), Some(value));
+        assert_eq!(clock.value(&[0, 0, u64::MAX]), None);
+
+        let factorial = MixedRadix::factorial(100);
+        assert_eq!(factorial.radices().len(), 20);
+        assert_eq!(
+            factorial.number(463).digits().collect::<Vec<_>>(),
+            vec![1, 0, 1, 4, 3]
+        );
+
+        let decimal = MixedRadix::fixed(10, 19);
+        for value in (0..100_000).chain([u64::MAX, u64::MAX - 12345]) {
+            assert_eq!(decimal.number(value).digits_sum(), value.digits_sum());
+        }
+    }
+
+    #[test]
+    fn test_fixed_radix_against_decimal() {
+        for sum in (1..=60).filter_map(NonZeroU8::new) {
+            let ints = MixedRadixWithDigitSum {
+                radix: MixedRadix::fixed(10, 19),
+                sum,
+            };
+
+            // The decimal sequencer walks through the hundreds, so the sparse sums take too long
+            if sum.get() >= 10 {
+                assert_eq!(
+                    ints.get_ints(1000).collect::<Vec<_>>(),
+                    integer::WithDigitSum(sum)
+                        .get_ints(1000)
+                        .collect::<Vec<_>>(),
+                    "sum {sum}"
+                );
+            }
+
+            for end in [0, 1, 100, 12_345, 10_000_000, 9_876_543] {
+                assert_eq!(
+                    ints.count(end),
+                    integer::WithDigitSum(sum)
+                        .get_ints(u32::MAX)
+                        .take_while(|value| *value < end)
+                        .count() as u64,
+                    "sum {sum}, end {end}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_against_brute_force() {
+        let limit = 100_000;
+
+        for radix in [
+            MixedRadix::factorial(20),
+            MixedRadix::new([60, 24]),
+            MixedRadix::new([2, 7, 3, 1000, 2]),
+            MixedRadix::fixed(3, 5),
+        ] {
+            for sum in (1..=30).filter_map(NonZeroU8::new) {
+                let expected = (0..limit)
+                    .filter(|value| {
+                        radix.number(*value).digits_sum() == sum.get() as u64
+                    })
+                    .collect::<Vec<_>>();
+
+                let ints = MixedRadixWithDigitSum {
+                    radix: radix.clone(),
+                    sum,
+                };
+
+                assert_eq!(
+                    ints.get_ints(u32::MAX)
+                        .take_while(|value| *value < limit)
+                        .collect::<Vec<_>>(),
+                    expected,
+                    "{radix:?}, sum {sum}"
+                );
+
+                for end in [0, 1, 7, 720, 1441, 54_321, limit] {
+                    assert_eq!(
+                        ints.count(end),
+                        expected.iter().filter(|value| **value < end).count()
+                            as u64,
+                        "{radix:?}, sum {sum}, end {end}"
+                    );
+                }
+            }
+        }
+
+        let ints = MixedRadixWithDigitSum {
+            radix: MixedRadix::factorial(20),
+            sum: NonZeroU8::new(1).unwrap(),
+        };
+        let all = ints.get_ints(u32::MAX).collect::<Vec<_>>();
+
+        // 0!, 1!, 2!, ..., 20! all fit into u64
+        assert_eq!(all.len(), 20);
+        assert_eq!(ints.count(u64::MAX), 20);
+    }
+}
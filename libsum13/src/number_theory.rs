@@ -0,0 +1,459 @@
+//! Here are all the sequencers that mix digits sums with some number theory
+
+mod niven;
+mod polynomial;
+mod primes;
+mod self_numbers;
+mod smith;
+
+pub use niven::{Niven, NivenWithDigitSum};
+pub use polynomial::PolynomialDigitSum;
+pub use primes::{PrimesWithDigitSum, Segment, SegmentedSieve};
+pub use self_numbers::{SelfNumbers, generators};
+pub use smith::{SmallestPrimeFactors, Smith, SmithWithDigitSum};
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU8;
+
+    use crate::{DigitSum, traits::SumSequencer};
+
+    use super::{
+        Niven, NivenWithDigitSum, PolynomialDigitSum, PrimesWithDigitSum,
+        SegmentedSieve, SelfNumbers, SmallestPrimeFactors, Smith,
+        SmithWithDigitSum, generators,
+    };
+
+    fn is_niven(value: &u64) -> bool {
+        *value != 0 && value.is_multiple_of(value.digits_sum())
+    }
+
+    #[test]
+    fn test_niven_against_brute_force() {
+        let limit = 100_000;
+        let expected = (0..limit).filter(is_niven).collect::<Vec<_>>();
+
+        assert_eq!(
+            Niven.get_ints(expected.len() as u32).collect::<Vec<_>>(),
+            expected
+        );
+
+        for end in [0, 1, 10, 11, 100, 1000, 12345, limit] {
+            assert_eq!(
+                Niven.count(end),
+                expected.iter().filter(|value| **value < end).count() as u64,
+                "end {end}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_niven_with_digit_sum_against_brute_force() {
+        let limit = 1_000_000;
+
+        for sum in (1..=54).filter_map(NonZeroU8::new) {
+            let expected = (0..limit)
+                .filter(|value| value.digits_sum() == sum.get() as u64)
+                .filter(is_niven)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                NivenWithDigitSum(sum)
+                    .get_ints(u32::MAX)
+                    .take_while(|value| *value < limit)
+                    .collect::<Vec<_>>(),
+                expected,
+                "sum {sum}"
+            );
+
+            for end in [0, 100, 9999, 123_456, limit] {
+                assert_eq!(
+                    NivenWithDigitSum(sum).count(end),
+                    expected.iter().filter(|value| **value < end).count()
+                        as u64,
+                    "sum {sum}, end {end}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_niven_count_parallel() {
+        let end = 10_000_000;
+
+        assert_eq!(
+            Niven.count(end),
+            Niven
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < end)
+                .count() as u64
+        );
+    }
+
+    #[test]
+    fn test_self_numbers() {
+        assert_eq!(
+            SelfNumbers.get_ints(16).collect::<Vec<_>>(),
+            vec![1, 3, 5, 7, 9, 20, 31, 42, 53, 64, 75, 86, 97, 108, 110, 121]
+        );
+
+        let limit = 200_000;
+        let mut generated = vec![false; limit as usize];
+
+        for value in 0..limit {
+            let next = value + value.digits_sum();
+
+            if next < limit {
+                generated[next as usize] = true;
+            }
+        }
+
+        let expected = (0..limit)
+            .filter(|value| !generated[*value as usize])
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            SelfNumbers
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < limit)
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_generators() {
+        assert_eq!(generators(0).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(generators(20).count(), 0);
+        assert_eq!(generators(101).collect::<Vec<_>>(), vec![91, 100]);
+        assert_eq!(
+            generators(10_000_000_000_001).collect::<Vec<_>>(),
+            vec![9_999_999_999_892, 9_999_999_999_901, 10_000_000_000_000]
+        );
+
+        // Near the top the generators must not overflow on the way
+        let expected = (u64::MAX - 200..=u64::MAX)
+            .filter(|elem| {
+                elem.checked_add(elem.digits_sum()) == Some(u64::MAX)
+            })
+            .collect::<Vec<_>>();
+        assert!(!expected.is_empty());
+        assert_eq!(generators(u64::MAX).collect::<Vec<_>>(), expected);
+
+        for value in 0..100_000u64 {
+            let expected = (0..=value)
+                .rev()
+                .take(100)
+                .filter(|elem| elem + elem.digits_sum() == value)
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .collect::<Vec<_>>();
+
+            assert_eq!(generators(value).collect::<Vec<_>>(), expected);
+        }
+    }
+
+    fn is_prime(value: u64) -> bool {
+        value >= 2
+            && (2..)
+                .take_while(|i| i * i <= value)
+                .all(|i| !value.is_multiple_of(i))
+    }
+
+    #[test]
+    fn test_segmented_sieve() {
+        let sieve = SegmentedSieve::new(1_000_050_000);
+
+        for (start, end) in [
+            (0, 1000),
+            (1, 2),
+            (999_000, 1_001_000),
+            (1_000_000_000, 1_000_050_000),
+        ] {
+            let segment = sieve.segment(start, end);
+
+            assert_eq!(
+                segment.primes().collect::<Vec<_>>(),
+                (start..end)
+                    .filter(|value| is_prime(*value))
+                    .collect::<Vec<_>>(),
+                "{start}..{end}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_primes_with_digit_sum_against_brute_force() {
+        let limit = 1_000_000;
+
+        for sum in (1..=50).filter_map(NonZeroU8::new) {
+            let expected = (0..limit)
+                .filter(|value| value.digits_sum() == sum.get() as u64)
+                .filter(|value| is_prime(*value))
+                .collect::<Vec<_>>();
+
+            // Some sums (like 2) may never have another prime, so the search must be limited
+            assert_eq!(
+                PrimesWithDigitSum(sum)
+                    .get_ints(expected.len() as u32)
+                    .collect::<Vec<_>>(),
+                expected,
+                "sum {sum}"
+            );
+
+            for end in [0, 3, 4, 100, 123_457, limit] {
+                assert_eq!(
+                    PrimesWithDigitSum(sum).count(end),
+                    expected.iter().filter(|value| **value < end).count()
+                        as u64,
+                    "sum {sum}, end {end}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_primes_count_against_sequence() {
+        let end = 50_000_000;
+
+        assert_eq!(
+            PrimesWithDigitSum::new(13).count(end),
+            PrimesWithDigitSum::new(13)
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < end)
+                .count() as u64
+        );
+    }
+
+    fn prime_factors(mut value: u64) -> Vec<u64> {
+        let mut factors = Vec::new();
+        let mut divisor = 2;
+
+        while divisor * divisor <= value {
+            while value.is_multiple_of(divisor) {
+                factors.push(divisor);
+                value /= divisor;
+            }
+            divisor += 1;
+        }
+
+        if value > 1 {
+            factors.push(value);
+        }
+
+        factors
+    }
+
+    #[test]
+    fn test_smallest_prime_factors() {
+        let mut factors = SmallestPrimeFactors::new();
+        factors.cover(300_000);
+
+        for value in 2..300_000 {
+            assert_eq!(
+                factors.factorize(value).collect::<Vec<_>>(),
+                prime_factors(value),
+                "{value}"
+            );
+        }
+
+        factors.cover(1_000_000);
+
+        for value in (2..1_000_000_000_000).step_by(9_999_999_967).chain([
+            999_999_999_989,
+            (1 << 32) - 1,
+            u64::MAX,
+        ]) {
+            assert_eq!(
+                factors.trial_factorize(value).collect::<Vec<_>>(),
+                prime_factors(value),
+                "{value}"
+            );
+        }
+    }
+
+    fn is_smith(value: &u64) -> bool {
+        let factors = prime_factors(*value);
+
+        factors.len() > 1
+            && factors
+                .iter()
+                .map(|factor| factor.digits_sum())
+                .sum::<u64>()
+                == value.digits_sum()
+    }
+
+    #[test]
+    fn test_smith() {
+        assert_eq!(
+            Smith.get_ints(16).collect::<Vec<_>>(),
+            vec![
+                4, 22, 27, 58, 85, 94, 121, 166, 202, 265, 274, 319, 346, 355,
+                378, 382
+            ]
+        );
+
+        let limit = 300_000;
+        let expected = (4..limit).filter(is_smith).collect::<Vec<_>>();
+
+        assert_eq!(
+            Smith.get_ints(expected.len() as u32).collect::<Vec<_>>(),
+            expected
+        );
+
+        for sum in (1..=50).filter_map(NonZeroU8::new) {
+            let with_sum = expected
+                .iter()
+                .copied()
+                .filter(|value| value.digits_sum() == sum.get() as u64)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                SmithWithDigitSum(sum)
+                    .get_ints(with_sum.len() as u32)
+                    .collect::<Vec<_>>(),
+                with_sum,
+                "sum {sum}"
+            );
+        }
+
+        // Past u32::MAX only the square roots have to be in the table
+        let mut factors = SmallestPrimeFactors::new();
+
+        for value in (1u64 << 32) - 1000..(1 << 32) + 1000 {
+            factors.cover(value.isqrt());
+
+            assert_eq!(
+                factors.is_smith_by_trial(value, value.digits_sum()),
+                is_smith(&value),
+                "{value}"
+            );
+        }
+    }
+
+    fn digits_sum_of(value: i128) -> u64 {
+        value
+            .unsigned_abs()
+            .to_string()
+            .bytes()
+            .map(|digit| (digit - b'0') as u64)
+            .sum()
+    }
+
+    #[test]
+    fn test_polynomial_against_brute_force() {
+        let limit = 30_000;
+
+        for sum in (1..=60).filter_map(NonZeroU8::new) {
+            for polynomial in [
+                PolynomialDigitSum::squares(sum),
+                PolynomialDigitSum::cubes(sum),
+                PolynomialDigitSum {
+                    coefficients: vec![2, -7, 3],
+                    sum,
+                },
+                PolynomialDigitSum {
+                    coefficients: vec![-1000, 0, 5, 0, 1],
+                    sum,
+                },
+            ] {
+                let expected = (0..limit)
+                    .map(|n| (n, polynomial.evaluate(n).unwrap()))
+                    .filter(|(_, value)| {
+                        digits_sum_of(*value) == sum.get() as u64
+                    })
+                    .collect::<Vec<_>>();
+
+                let got = polynomial.get_values(limit).collect::<Vec<_>>();
+
+                assert_eq!(got, expected, "{polynomial:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_polynomial_residues() {
+        // Squares are 0, 1, 4 or 7 modulo 9
+        assert_eq!(
+            PolynomialDigitSum::squares(NonZeroU8::new(2).unwrap()).residues(),
+            vec![]
+        );
+        assert_eq!(
+            PolynomialDigitSum::squares(NonZeroU8::new(2).unwrap())
+                .get_values(u64::MAX)
+                .count(),
+            0
+        );
+        assert_eq!(
+            PolynomialDigitSum::squares(NonZeroU8::new(13).unwrap()).residues(),
+            vec![2, 7]
+        );
+
+        assert_eq!(
+            PolynomialDigitSum::cubes(NonZeroU8::new(1).unwrap())
+                .get_values(10_001)
+                .map(|(n, _)| n)
+                .collect::<Vec<_>>(),
+            vec![1, 10, 100, 1000, 10_000]
+        );
+
+        // Stops once the value doesn't fit into i128
+        let mut coefficients = vec![0; 13];
+        coefficients[12] = 1;
+        let huge = PolynomialDigitSum {
+            coefficients,
+            sum: NonZeroU8::new(1).unwrap(),
+        };
+
+        assert_eq!(
+            huge.get_values(u64::MAX)
+                .map(|(n, _)| n)
+                .collect::<Vec<_>>(),
+            vec![1, 10, 100, 1000]
+        );
+    }
+
+    #[test]
+    fn test_polynomial_degenerate_and_overflowing_classes() {
+        // Zero is in the class of 9, but its digits sum is 0
+        for coefficients in [vec![], vec![0], vec![0, 0, 0]] {
+            let zero = PolynomialDigitSum {
+                coefficients,
+                sum: NonZeroU8::new(9).unwrap(),
+            };
+            assert_eq!(zero.residues().len(), 9);
+            assert_eq!(zero.get_values(1000).count(), 0);
+        }
+
+        let sum = NonZeroU8::new(13).unwrap();
+
+        let constant = PolynomialDigitSum {
+            coefficients: vec![-49],
+            sum,
+        };
+        assert_eq!(
+            constant.get_values(1000).collect::<Vec<_>>(),
+            (0..1000).map(|n| (n, -49)).collect::<Vec<_>>()
+        );
+
+        // The classes overflow one by one, each of them is walked up to its own last value
+        for sum in (1..=20).filter_map(NonZeroU8::new) {
+            let steep = PolynomialDigitSum {
+                coefficients: vec![0, 0, 0, 0, i64::MAX],
+                sum,
+            };
+
+            let expected = (0..)
+                .map_while(|n| Some((n, steep.evaluate(n)?)))
+                .filter(|(_, value)| digits_sum_of(*value) == sum.get() as u64)
+                .collect::<Vec<_>>();
+
+            assert_eq!(
+                steep.get_values(u64::MAX).collect::<Vec<_>>(),
+                expected,
+                "sum {sum}"
+            );
+        }
+    }
+}
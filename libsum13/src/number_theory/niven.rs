@@ -0,0 +1,142 @@
+use std::num::NonZeroU8;
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    combinatorics::{Decimal, count_below},
+    impl_mut_for_refmut,
+    integer::{DigitSumCounter, FutureLooking},
+    new_expect,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+/// Starting from here, counting is split between the threads: one digits sum per task.
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: u64 = 1_000_000;
+
+/// Niven (or Harshad) numbers: the ones that are divisible by their own digits sum.
+pub struct Niven;
+impl_mut_for_refmut!(Niven);
+
+impl SumSequencer for Niven {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        DigitSumCounter::new(0..)
+            .filter(|(elem, digits_sum)| {
+                *digits_sum != 0 && elem % digits_sum == 0
+            })
+            .map(|(elem, _)| elem)
+            .take(iterations as usize)
+    }
+}
+
+impl Niven {
+    /// Count all the Niven numbers in range `0..end`.
+    ///
+    /// Each possible digits sum is counted on its own, which makes it easy to parallelize.
+    pub fn count(&self, end: u64) -> u64 {
+        let max_sum = 9 * end.to_string().len() as u64;
+        let count_for = |sum: u64| {
+            count_divisible(
+                NonZeroU8::new(sum as u8).expect("Sum starts from 1"),
+                end,
+            )
+        };
+
+        #[cfg(feature = "parallel")]
+        if end >= PARALLEL_THRESHOLD {
+            return (1..=max_sum).into_par_iter().map(count_for).sum();
+        }
+
+        (1..=max_sum).map(count_for).sum()
+    }
+}
+
+/// Niven numbers which digits sum up to the given number, i.e. the multiples of it.
+pub struct NivenWithDigitSum(pub NonZeroU8);
+new_expect!(NivenWithDigitSum);
+impl_mut_for_refmut!(NivenWithDigitSum);
+
+impl SumSequencer for NivenWithDigitSum {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let sum = self.0.get() as u64;
+
+        FutureLooking(self.0)
+            .get_ints(u32::MAX)
+            .filter(move |value| value % sum == 0)
+            .take(iterations as usize)
+    }
+}
+
+impl NivenWithDigitSum {
+    /// Count all the numbers in range `0..end` which digits sum up to `self.0` and which are divisible by it.
+    pub fn count(&self, end: u64) -> u64 {
+        count_divisible(self.0, end)
+    }
+}
+
+/// Digit DP over (digits sum, remainder): count all the numbers in range `0..end`
+/// which digits sum up to `sum` and which are divisible by `sum`.
+fn count_divisible(sum: NonZeroU8, end: u64) -> u64 {
+    let modulus = sum.get() as usize;
+    let sum = sum.get() as usize;
+    let end_digits = Decimal::new(end);
+    let length = end_digits.len();
+
+    // Nothing is below zero
+    if length == 0 {
+        return 0;
+    }
+
+    let mut powers = vec![1 % modulus; length];
+    for digits in 1..length {
+        powers[digits] = powers[digits - 1] * 10 % modulus;
+    }
+
+    // ways[r][t][q] is the number of r-digit strings (leading zeros allowed)
+    // with digits sum t and value giving the remainder q
+    let mut ways = vec![vec![vec![0u64; modulus]; sum + 1]; length];
+    ways[0][0][0] = 1;
+
+    for digits in 1..length {
+        for total in 0..=sum {
+            for remainder in 0..modulus {
+                ways[digits][total][remainder] = (0..=usize::min(9, total))
+                    .map(|digit| {
+                        let shift = digit * powers[digits - 1] % modulus;
+                        let rest = (remainder + modulus - shift) % modulus;
+
+                        ways[digits - 1][total - digit][rest]
+                    })
+                    .sum();
+            }
+        }
+    }
+
+    // Leading zeros are allowed here, so the shorter numbers are counted along the way
+    let (result, _) = count_below(
+        end_digits.digits(),
+        (0, 0),
+        |(used, remainder), position, digit| {
+            let after = length - position - 1;
+            let digit = digit as usize;
+
+            if used + digit > sum {
+                return 0;
+            }
+
+            let prefix = (remainder * 10 + digit) * powers[after] % modulus;
+            let needed = (modulus - prefix) % modulus;
+
+            ways[after][sum - used - digit][needed]
+        },
+        |(used, remainder), _, digit| {
+            let digit = digit as usize;
+
+            (used + digit <= sum)
+                .then_some((used + digit, (remainder * 10 + digit) % modulus))
+        },
+    );
+
+    result
+}
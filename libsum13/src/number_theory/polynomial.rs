@@ -0,0 +1,228 @@
+use std::num::NonZeroU8;
+
+/// Values `f(n)` of an integer polynomial which digits sum up to `sum`, in order of `n`.
+///
+/// The digits sum of a negative value is the digits sum of its absolute value.
+///
+/// Digits sum is congruent to the number itself modulo 9, and `f(n) mod 9` only depends on `n mod 9`.
+/// So the whole residue classes of `n` that can never reach `sum` are skipped:
+/// every other class `n = 9k + r` is walked on its own, as a polynomial of `k`.
+#[derive(Debug, Clone)]
+pub struct PolynomialDigitSum {
+    /// Coefficients of the polynomial, starting from the constant one.
+    pub coefficients: Vec<i64>,
+    pub sum: NonZeroU8,
+}
+
+impl PolynomialDigitSum {
+    pub fn squares(sum: NonZeroU8) -> Self {
+        Self {
+            coefficients: vec![0, 0, 1],
+            sum,
+        }
+    }
+
+    pub fn cubes(sum: NonZeroU8) -> Self {
+        Self {
+            coefficients: vec![0, 0, 0, 1],
+            sum,
+        }
+    }
+
+    pub fn evaluate(&self, n: u64) -> Option<i128> {
+        self.coefficients
+            .iter()
+            .rev()
+            .try_fold(0i128, |acc, coefficient| {
+                acc.checked_mul(n as i128)?
+                    .checked_add(*coefficient as i128)
+            })
+    }
+
+    /// Residues of `n` modulo 9 for which `f(n)` may have the right digits sum.
+    pub fn residues(&self) -> Vec<u64> {
+        let target = self.sum.get() as i128 % 9;
+        // With no negative coefficients f(n) is never negative
+        let signed =
+            self.coefficients.iter().any(|coefficient| *coefficient < 0);
+
+        (0..9)
+            .filter(|residue| {
+                let value = self
+                    .evaluate(*residue)
+                    .expect("Polynomial overflows on the first 9 values");
+
+                // The sign of f(n) can differ within the class, so both are kept
+                value.rem_euclid(9) == target
+                    || signed && (-value).rem_euclid(9) == target
+            })
+            .collect()
+    }
+
+    /// All the pairs `(n, f(n))` for `n` in range `0..end` with the right digits sum.
+    ///
+    /// Every residue class stops on its own once its `f(n)` doesn't fit into i128.
+    /// Panics if the first `degree + 1` values of some residue class don't fit already.
+    pub fn get_values(
+        &self,
+        end: u64,
+    ) -> impl Iterator<Item = (u64, i128)> + use<> {
+        let sum = self.sum.get() as u64;
+        let mut walkers = self
+            .residues()
+            .into_iter()
+            .filter(|residue| *residue < end)
+            .map(|residue| ClassWalker::new(self, residue))
+            .collect::<Vec<_>>();
+        let mut current = 0;
+
+        std::iter::from_fn(move || {
+            loop {
+                let walker = walkers.get_mut(current)?;
+
+                // The classes go in turn, so this is the smallest `n` that is left
+                if walker.n >= end {
+                    walkers.clear();
+                    return None;
+                }
+
+                let found = walker.digits.sum == sum;
+                let result = (walker.n, walker.value);
+
+                if walker.advance() {
+                    current += 1;
+                } else {
+                    // Only this class is over, the rest keep their turns
+                    walkers.remove(current);
+                }
+
+                if current >= walkers.len() {
+                    current = 0;
+                }
+
+                if found {
+                    return Some(result);
+                }
+            }
+        })
+    }
+}
+
+/// Walks through `f(9k + r)` for `k = 0, 1, 2, ...` with the forward differences,
+/// so that every step is additions only.
+struct ClassWalker {
+    n: u64,
+    value: i128,
+    /// `differences[j]` is the (j + 1)-th forward difference at the current `k`
+    differences: Vec<i128>,
+    digits: DecimalDigits,
+}
+
+impl ClassWalker {
+    fn new(polynomial: &PolynomialDigitSum, residue: u64) -> Self {
+        let degree = polynomial.coefficients.len();
+
+        let mut table = (0..=degree as u64)
+            .map(|k| {
+                polynomial
+                    .evaluate(9 * k + residue)
+                    .expect("Polynomial overflows on the first values")
+            })
+            .collect::<Vec<_>>();
+
+        // In-place forward differences: table[j] becomes the j-th difference at k = 0
+        for order in 1..table.len() {
+            for i in (order..table.len()).rev() {
+                table[i] -= table[i - 1];
+            }
+        }
+
+        let value = table[0];
+
+        Self {
+            n: residue,
+            value,
+            differences: table[1..].to_vec(),
+            digits: DecimalDigits::new(value.unsigned_abs()),
+        }
+    }
+
+    /// Returns false once the next value doesn't fit.
+    fn advance(&mut self) -> bool {
+        // The zero polynomial has no differences at all, it stays zero
+        let step = self.differences.first().copied().unwrap_or(0);
+        let Some(next) = self.value.checked_add(step) else {
+            return false;
+        };
+
+        for order in 1..self.differences.len() {
+            let Some(difference) = self.differences[order - 1]
+                .checked_add(self.differences[order])
+            else {
+                return false;
+            };
+
+            self.differences[order - 1] = difference;
+        }
+
+        let Some(n) = self.n.checked_add(9) else {
+            return false;
+        };
+
+        if self.value >= 0 && next >= self.value {
+            self.digits.add((next - self.value) as u128);
+        } else {
+            self.digits = DecimalDigits::new(next.unsigned_abs());
+        }
+
+        self.value = next;
+        self.n = n;
+
+        true
+    }
+}
+
+/// Decimal digits of a number (least significant first) with their sum,
+/// which is kept up to date while adding to the number.
+struct DecimalDigits {
+    digits: Vec<u8>,
+    sum: u64,
+}
+
+impl DecimalDigits {
+    fn new(mut value: u128) -> Self {
+        let mut digits = Vec::new();
+
+        while value != 0 {
+            digits.push((value % 10) as u8);
+            value /= 10;
+        }
+
+        let sum = digits.iter().map(|digit| *digit as u64).sum();
+
+        Self { digits, sum }
+    }
+
+    /// Every carry takes 10 from one digit and gives 1 to the next, so only the touched digits are updated.
+    fn add(&mut self, mut addition: u128) {
+        let mut carry = 0;
+        let mut position = 0;
+
+        while addition != 0 || carry != 0 {
+            if position == self.digits.len() {
+                self.digits.push(0);
+            }
+
+            let digit = self.digits[position] as u128 + addition % 10 + carry;
+            let new_digit = (digit % 10) as u8;
+
+            self.sum =
+                self.sum + new_digit as u64 - self.digits[position] as u64;
+            self.digits[position] = new_digit;
+
+            carry = digit / 10;
+            addition /= 10;
+            position += 1;
+        }
+    }
+}
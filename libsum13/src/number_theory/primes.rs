@@ -0,0 +1,209 @@
+use std::num::NonZeroU8;
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{
+    either_iterator::EitherIterator,
+    impl_mut_for_refmut,
+    integer::IntsWithDigitSumInBounds,
+    new_expect,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+/// IntsWithDigitSumInBounds works with whole hundreds, so the segments must be made of them.
+const SEGMENT: u64 = 1_000_000;
+
+/// Segmented sieve of Eratosthenes.
+///
+/// Only the base primes up to the square root of the range are kept,
+/// each segment is then sieved on its own (and possibly on its own thread).
+pub struct SegmentedSieve {
+    base: Vec<u64>,
+    end: u64,
+}
+
+impl SegmentedSieve {
+    /// A sieve that is able to sieve any segment in range `0..end`.
+    pub fn new(end: u64) -> Self {
+        let limit = end.isqrt() as usize + 1;
+        let mut is_prime = vec![true; limit + 1];
+        is_prime[0] = false;
+        is_prime[1] = false;
+
+        let mut i = 2;
+        while i * i <= limit {
+            if is_prime[i] {
+                (i * i..=limit)
+                    .step_by(i)
+                    .for_each(|multiple| is_prime[multiple] = false);
+            }
+            i += 1;
+        }
+
+        let base = (0..=limit as u64)
+            .filter(|value| is_prime[*value as usize])
+            .collect();
+
+        Self { base, end }
+    }
+
+    pub fn end(&self) -> u64 {
+        self.end
+    }
+
+    /// Sieve all the numbers in range `start..end`.
+    pub fn segment(&self, start: u64, end: u64) -> Segment {
+        assert!(end <= self.end, "The segment is out of the sieve's range");
+
+        let mut is_prime = vec![true; end.saturating_sub(start) as usize];
+
+        for prime in
+            self.base.iter().take_while(|prime| **prime * **prime < end)
+        {
+            let first = u64::max(prime * prime, start.next_multiple_of(*prime));
+
+            (first..end).step_by(*prime as usize).for_each(|multiple| {
+                is_prime[(multiple - start) as usize] = false
+            });
+        }
+
+        (start..u64::min(2, end)).for_each(|value| {
+            is_prime[(value - start) as usize] = false;
+        });
+
+        Segment { start, is_prime }
+    }
+}
+
+/// A sieved range of numbers.
+pub struct Segment {
+    start: u64,
+    is_prime: Vec<bool>,
+}
+
+impl Segment {
+    pub fn is_prime(&self, value: u64) -> bool {
+        self.is_prime[(value - self.start) as usize]
+    }
+
+    pub fn primes(&self) -> impl Iterator<Item = u64> + use<'_> {
+        (self.start..)
+            .zip(self.is_prime.iter())
+            .filter(|(_, is_prime)| **is_prime)
+            .map(|(value, _)| value)
+    }
+}
+
+/// Primes which digits sum up to the given number.
+///
+/// Only the numbers with the right digits sum are looked up in the sieve.
+/// Any number with digits sum divisible by 3 is divisible by 3 itself,
+/// so for such sums there is either 3 or nothing at all.
+/// The same goes for the sum of 1: powers of 10 are never prime.
+pub struct PrimesWithDigitSum(pub NonZeroU8);
+new_expect!(PrimesWithDigitSum);
+impl_mut_for_refmut!(PrimesWithDigitSum);
+
+impl SumSequencer for PrimesWithDigitSum {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let sum = self.0;
+
+        if sum.get() == 1 || sum.get().is_multiple_of(3) {
+            return EitherIterator::Left(
+                std::iter::once(3)
+                    .filter(move |_| sum.get() == 3)
+                    .take(iterations as usize),
+            );
+        }
+
+        #[cfg(feature = "parallel")]
+        let batch = rayon::current_num_threads() as u64;
+        #[cfg(not(feature = "parallel"))]
+        let batch = 1;
+        let mut sieve = SegmentedSieve::new(batch * SEGMENT);
+
+        // Each batch sieves one segment per thread, the batches go one after another
+        EitherIterator::Right(
+            (0..)
+                .flat_map(move |i: u64| {
+                    let start = i * batch * SEGMENT;
+                    let end = start + batch * SEGMENT;
+
+                    if end > sieve.end() {
+                        sieve = SegmentedSieve::new(end * 2);
+                    }
+
+                    #[cfg(feature = "parallel")]
+                    let segments = (0..batch).into_par_iter();
+                    #[cfg(not(feature = "parallel"))]
+                    let segments = 0..batch;
+
+                    segments
+                        .map(|j| {
+                            let start = start + j * SEGMENT;
+                            primes_in(&sieve, sum, start, start + SEGMENT)
+                        })
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .flatten()
+                })
+                .take(iterations as usize),
+        )
+    }
+}
+
+impl PrimesWithDigitSum {
+    /// Count all the primes in range `0..end` which digits sum up to `self.0`.
+    pub fn count(&self, end: u64) -> u64 {
+        let sum = self.0;
+
+        if sum.get() == 1 || sum.get().is_multiple_of(3) {
+            return (sum.get() == 3 && end > 3) as u64;
+        }
+
+        let sieve = SegmentedSieve::new(end);
+
+        #[cfg(feature = "parallel")]
+        let segments = (0..end.div_ceil(SEGMENT)).into_par_iter();
+        #[cfg(not(feature = "parallel"))]
+        let segments = 0..end.div_ceil(SEGMENT);
+
+        segments
+            .map(|i| {
+                let start = i * SEGMENT;
+                let end = u64::min(end, start + SEGMENT);
+
+                primes_in(&sieve, sum, start, end).len() as u64
+            })
+            .sum()
+    }
+}
+
+/// All the primes in range `start..end` which digits sum up to `sum`.
+/// The segment isn't even sieved if there is no number with the right digits sum.
+fn primes_in(
+    sieve: &SegmentedSieve,
+    sum: NonZeroU8,
+    start: u64,
+    end: u64,
+) -> Vec<u64> {
+    let mut candidates = IntsWithDigitSumInBounds {
+        start,
+        end: end.next_multiple_of(100),
+        sum,
+    }
+    .get_ints()
+    .take_while(|value| *value < end)
+    .peekable();
+
+    if candidates.peek().is_none() {
+        return Vec::new();
+    }
+
+    let segment = sieve.segment(start, end);
+
+    candidates
+        .filter(|value| segment.is_prime(*value))
+        .collect()
+}
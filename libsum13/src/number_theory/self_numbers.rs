@@ -0,0 +1,47 @@
+use crate::{
+    impl_mut_for_refmut,
+    integer::DigitSumCounter,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+/// Every generator `n` of `m` lies in `m - 180..=m`, since u64 digits sum can't be greater.
+/// The power of two makes the window index a cheap remainder.
+const WINDOW: u64 = 256;
+
+/// Self (or Colombian) numbers: the ones that can't be written as `n + digits_sum(n)`.
+///
+/// Since `n + digits_sum(n) >= n`, once `n` is reached, every possible generator of `n` has been seen.
+/// So only the window of the following `WINDOW` values is kept.
+pub struct SelfNumbers;
+impl_mut_for_refmut!(SelfNumbers);
+
+impl SumSequencer for SelfNumbers {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let mut generated = [false; WINDOW as usize];
+
+        DigitSumCounter::new(0..)
+            .filter_map(move |(elem, digits_sum)| {
+                generated[((elem + digits_sum) % WINDOW) as usize] = true;
+
+                let slot = (elem % WINDOW) as usize;
+                let is_self = !generated[slot];
+                generated[slot] = false;
+
+                is_self.then_some(elem)
+            })
+            .take(iterations as usize)
+    }
+}
+
+/// All the `n` such that `n + digits_sum(n) == value`, in ascending order.
+///
+/// Digits sum of `n` is no more than 9 per digit, so every generator lies within `9 * digits(value)` of `value`.
+pub fn generators(value: u64) -> impl Iterator<Item = u64> + use<> {
+    let lowest = value.saturating_sub(9 * value.to_string().len() as u64);
+
+    DigitSumCounter::new(lowest..=value)
+        .filter(move |(elem, digits_sum)| {
+            elem.checked_add(*digits_sum) == Some(value)
+        })
+        .map(|(elem, _)| elem)
+}
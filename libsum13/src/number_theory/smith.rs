@@ -0,0 +1,193 @@
+use std::num::NonZeroU8;
+
+use crate::{
+    DigitSum, impl_mut_for_refmut,
+    integer::{DigitSumCounter, FutureLooking},
+    new_expect,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+const INITIAL_SIZE: u64 = 1 << 16;
+
+/// Table of the smallest prime factor for every number in range `0..self.end()`.
+///
+/// It grows segment by segment: every new segment is twice as big as the whole table,
+/// and is sieved with the primes that are already known.
+pub struct SmallestPrimeFactors(Vec<u32>);
+
+impl Default for SmallestPrimeFactors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SmallestPrimeFactors {
+    pub fn new() -> Self {
+        let mut table = Self(vec![0, 1]);
+        table.grow(INITIAL_SIZE as usize);
+        table
+    }
+
+    pub fn end(&self) -> u64 {
+        self.0.len() as u64
+    }
+
+    /// Grow the table until `value` is in it.
+    pub fn cover(&mut self, value: u64) {
+        assert!(
+            value <= u32::MAX as u64,
+            "The table only holds factors of u32 values"
+        );
+
+        while self.end() <= value {
+            self.grow(self.0.len() * 2);
+        }
+    }
+
+    pub fn smallest(&self, value: u64) -> u64 {
+        self.0[value as usize] as u64
+    }
+
+    /// Prime factors of `value` in ascending order, counted with multiplicity.
+    pub fn factorize(&self, mut value: u64) -> impl Iterator<Item = u64> {
+        std::iter::from_fn(move || {
+            if value <= 1 {
+                return None;
+            }
+
+            let factor = self.smallest(value);
+            value /= factor;
+
+            Some(factor)
+        })
+    }
+
+    /// Prime factors of `value` in ascending order, by trial division over the primes in the table.
+    ///
+    /// The table only has to cover `value.isqrt()`, so it stays small even for the huge values.
+    pub fn trial_factorize(&self, mut value: u64) -> impl Iterator<Item = u64> {
+        let mut divisor = 2;
+
+        std::iter::from_fn(move || {
+            loop {
+                if value <= 1 {
+                    return None;
+                }
+
+                // Nothing up to the square root divides it, so what is left is prime
+                if divisor > value / divisor {
+                    return Some(std::mem::replace(&mut value, 1));
+                }
+
+                if value.is_multiple_of(divisor) {
+                    value /= divisor;
+                    return Some(divisor);
+                }
+
+                divisor += 1;
+                while divisor <= value / divisor
+                    && self.smallest(divisor) != divisor
+                {
+                    divisor += 1;
+                }
+            }
+        })
+    }
+
+    fn grow(&mut self, end: usize) {
+        let start = self.0.len();
+        self.0.resize(end, 0);
+
+        for prime in 2..=(end - 1).isqrt() {
+            // Every number that is left unmarked by the smaller primes is prime itself
+            match self.0[prime] {
+                0 => self.0[prime] = prime as u32,
+                factor if factor as usize != prime => continue,
+                _ => {}
+            }
+
+            let first =
+                usize::max(prime * prime, start.next_multiple_of(prime));
+
+            for multiple in (first..end).step_by(prime) {
+                if self.0[multiple] == 0 {
+                    self.0[multiple] = prime as u32;
+                }
+            }
+        }
+
+        for value in start..end {
+            if self.0[value] == 0 {
+                self.0[value] = value as u32;
+            }
+        }
+    }
+
+    /// A composite number which digits sum is the same as the sum of its prime factors' digits sums.
+    pub fn is_smith(&self, value: u64, digits_sum: u64) -> bool {
+        value > 3
+            && self.smallest(value) != value
+            && self
+                .factorize(value)
+                .map(|factor| factor.digits_sum())
+                .sum::<u64>()
+                == digits_sum
+    }
+
+    /// The same as `is_smith`, but by trial division, so the table only has to cover `value.isqrt()`.
+    pub fn is_smith_by_trial(&self, value: u64, digits_sum: u64) -> bool {
+        let (count, factors_sum) = self.trial_factorize(value).fold(
+            (0, 0),
+            |(count, factors_sum), factor| {
+                (count + 1, factors_sum + factor.digits_sum())
+            },
+        );
+
+        count > 1 && factors_sum == digits_sum
+    }
+}
+
+/// Smith numbers: composite numbers which digits sum is the same
+/// as the sum of their prime factors' digits sums.
+///
+/// For example, `22 = 2 * 11` and `2 + 2 = 2 + (1 + 1)`.
+/// The candidates are factorized by trial division, so the table only grows up to their square roots.
+pub struct Smith;
+impl_mut_for_refmut!(Smith);
+
+impl SumSequencer for Smith {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let mut factors = SmallestPrimeFactors::new();
+
+        DigitSumCounter::new(0..)
+            .filter(move |(elem, digits_sum)| {
+                factors.cover(elem.isqrt());
+                factors.is_smith_by_trial(*elem, *digits_sum)
+            })
+            .map(|(elem, _)| elem)
+            .take(iterations as usize)
+    }
+}
+
+/// Smith numbers which digits sum up to the given number.
+///
+/// Only the numbers with the right digits sum are factorized, the rest are skipped entirely.
+/// Those are factorized by trial division, so the table only grows up to their square roots.
+pub struct SmithWithDigitSum(pub NonZeroU8);
+new_expect!(SmithWithDigitSum);
+impl_mut_for_refmut!(SmithWithDigitSum);
+
+impl SumSequencer for SmithWithDigitSum {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let sum = self.0.get() as u64;
+        let mut factors = SmallestPrimeFactors::new();
+
+        FutureLooking(self.0)
+            .get_ints(u32::MAX)
+            .filter(move |value| {
+                factors.cover(value.isqrt());
+                factors.is_smith_by_trial(*value, sum)
+            })
+            .take(iterations as usize)
+    }
+}
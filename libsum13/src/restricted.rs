@@ -0,0 +1,307 @@
+//! Here are all the sequencers that yield only some special subset of the numbers with the given digits sum
+
+mod distinct;
+mod non_decreasing;
+mod palindrome;
+mod sparse;
+
+pub use distinct::DistinctDigits;
+pub use non_decreasing::NonDecreasingDigits;
+pub use palindrome::Palindromes;
+pub use sparse::{NonZeroDigits, WithNonZeroDigits};
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroU8;
+
+    use crate::{DigitSum, string, traits::SumSequencer};
+
+    use super::{
+        DistinctDigits, NonDecreasingDigits, NonZeroDigits, Palindromes,
+        WithNonZeroDigits,
+    };
+
+    const LIMIT: u64 = 100_000;
+
+    fn is_palindrome(value: &u64) -> bool {
+        let digits = value.to_string().into_bytes();
+        digits.iter().eq(digits.iter().rev())
+    }
+
+    fn brute_force_palindromes(sum: NonZeroU8) -> Vec<u64> {
+        string::WithDigitSum(sum)
+            .get_ints(u32::MAX)
+            .take_while(|value| *value < LIMIT)
+            .filter(is_palindrome)
+            .collect()
+    }
+
+    #[test]
+    fn test_palindromes_against_strings() {
+        for sum in (1..=45).filter_map(NonZeroU8::new) {
+            let expected = brute_force_palindromes(sum);
+
+            let got = Palindromes(sum)
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < LIMIT)
+                .collect::<Vec<_>>();
+
+            assert_eq!(got, expected, "sum {sum}");
+        }
+    }
+
+    #[test]
+    fn test_palindromes_count() {
+        for sum in (1..=45).filter_map(NonZeroU8::new) {
+            let expected = brute_force_palindromes(sum);
+
+            for end in [0, 1, 10, 99, 100, 121, 5000, 12321, 50005, LIMIT] {
+                assert_eq!(
+                    Palindromes(sum).count(end),
+                    expected.iter().filter(|value| **value < end).count()
+                        as u64,
+                    "sum {sum}, end {end}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_even_length_palindromes_have_even_sum() {
+        for sum in
+            [2, 4, 5, 6, 12, 13, 18].map(|sum| NonZeroU8::new(sum).unwrap())
+        {
+            let all = Palindromes(sum).get_ints(u32::MAX).collect::<Vec<_>>();
+            let has_even_length =
+                |value: &u64| value.to_string().len().is_multiple_of(2);
+
+            if sum.get() % 2 == 1 {
+                assert!(!all.iter().any(has_even_length), "sum {sum}");
+            } else {
+                assert!(all.iter().any(has_even_length), "sum {sum}");
+            }
+
+            assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+            assert_eq!(
+                Palindromes(sum).count(u64::MAX),
+                all.len() as u64,
+                "sum {sum}"
+            );
+        }
+
+        // 2, 11, 101, ..., 10^19 + 1
+        assert_eq!(Palindromes::new(2).get_ints(u32::MAX).count(), 20);
+        assert_eq!(
+            Palindromes::new(2).get_ints(u32::MAX).last(),
+            Some(10_000_000_000_000_000_001)
+        );
+    }
+
+    fn brute_force(
+        sum: NonZeroU8,
+        limit: u64,
+        filter: impl Fn(&[u8]) -> bool,
+    ) -> Vec<u64> {
+        (0..limit)
+            .filter(|value| value.digits_sum() == sum.get() as u64)
+            .filter(|value| filter(value.to_string().as_bytes()))
+            .collect()
+    }
+
+    fn are_distinct(digits: &[u8]) -> bool {
+        digits
+            .iter()
+            .enumerate()
+            .all(|(i, digit)| !digits[..i].contains(digit))
+    }
+
+    fn are_non_decreasing(digits: &[u8]) -> bool {
+        digits.windows(2).all(|pair| pair[0] <= pair[1])
+    }
+
+    #[test]
+    fn test_distinct_digits_against_brute_force() {
+        let limit = 1_000_000;
+
+        for sum in (1..=45).filter_map(NonZeroU8::new) {
+            let expected = brute_force(sum, limit, are_distinct);
+
+            let got = DistinctDigits(sum)
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < limit)
+                .collect::<Vec<_>>();
+
+            assert_eq!(got, expected, "sum {sum}");
+
+            for end in [0, 1, 10, 98, 1000, 12345, 102_345, 987_654, limit] {
+                assert_eq!(
+                    DistinctDigits(sum).count(end),
+                    expected.iter().filter(|value| **value < end).count()
+                        as u64,
+                    "sum {sum}, end {end}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_distinct_digits_are_finite() {
+        // 9 + 9 * 9 + 9 * 9 * 8 + ... numbers with distinct digits in total
+        let total = (1..=10)
+            .map(|length| 9 * (1..length).map(|i| 10 - i).product::<u64>())
+            .sum::<u64>();
+
+        assert_eq!(
+            (1..=45)
+                .map(|sum| DistinctDigits::new(sum).count_all())
+                .sum::<u64>(),
+            total
+        );
+
+        for sum in [1, 13, 30, 44, 45] {
+            let all = DistinctDigits::new(sum).get_ints(u32::MAX);
+
+            assert_eq!(
+                all.count() as u64,
+                DistinctDigits::new(sum).count_all(),
+                "sum {sum}"
+            );
+        }
+
+        assert_eq!(
+            DistinctDigits::new(45).get_ints(u32::MAX).last(),
+            Some(9_876_543_210)
+        );
+        assert_eq!(DistinctDigits::new(46).get_ints(u32::MAX).count(), 0);
+    }
+
+    #[test]
+    fn test_non_decreasing_digits_against_brute_force() {
+        let limit = 1_000_000;
+
+        for sum in (1..=54).filter_map(NonZeroU8::new) {
+            let expected = brute_force(sum, limit, are_non_decreasing);
+
+            let got = NonDecreasingDigits(sum)
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < limit)
+                .collect::<Vec<_>>();
+
+            assert_eq!(got, expected, "sum {sum}");
+
+            for end in [0, 1, 10, 99, 1000, 12345, 123_456, 999_999, limit] {
+                assert_eq!(
+                    NonDecreasingDigits(sum).count(end),
+                    expected.iter().filter(|value| **value < end).count()
+                        as u64,
+                    "sum {sum}, end {end}"
+                );
+            }
+
+            // All the partitions have no more than 6 parts
+            if sum.get() <= 6 {
+                assert_eq!(
+                    NonDecreasingDigits(sum).count_all(),
+                    expected.len() as u64
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_decreasing_digits_stop_at_u64() {
+        for sum in [13, 20, 40, 100, 180] {
+            let all = NonDecreasingDigits::new(sum)
+                .get_ints(u32::MAX)
+                .collect::<Vec<_>>();
+
+            assert!(all.windows(2).all(|pair| pair[0] < pair[1]));
+            assert_eq!(
+                all.len() as u64,
+                NonDecreasingDigits::new(sum).count(u64::MAX),
+                "sum {sum}"
+            );
+        }
+
+        assert_eq!(NonDecreasingDigits::new(13).count_all(), {
+            NonDecreasingDigits::new(13).get_ints(u32::MAX).count() as u64
+        });
+    }
+
+    fn count_nonzero(digits: &[u8]) -> u8 {
+        digits.iter().filter(|digit| **digit != b'0').count() as u8
+    }
+
+    #[test]
+    fn test_nonzero_digits_against_brute_force() {
+        let limit = 200_000;
+
+        for sum in (1..=30).filter_map(NonZeroU8::new) {
+            for count in 0..=6 {
+                for nonzero in [
+                    NonZeroDigits::Exactly(count),
+                    NonZeroDigits::AtMost(count),
+                ] {
+                    let expected =
+                        brute_force(sum, limit, |digits| match nonzero {
+                            NonZeroDigits::Exactly(count) => {
+                                count_nonzero(digits) == count
+                            }
+                            NonZeroDigits::AtMost(count) => {
+                                count_nonzero(digits) <= count
+                            }
+                        });
+
+                    let ints = WithNonZeroDigits { sum, nonzero };
+
+                    let got = ints
+                        .get_ints()
+                        .take_while(|value| *value < limit)
+                        .collect::<Vec<_>>();
+
+                    assert_eq!(got, expected, "sum {sum}, {nonzero:?}");
+
+                    for end in [0, 1, 10, 99, 1000, 12345, 100_500, limit] {
+                        assert_eq!(
+                            ints.count(end),
+                            expected
+                                .iter()
+                                .filter(|value| **value < end)
+                                .count() as u64,
+                            "sum {sum}, {nonzero:?}, end {end}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_nonzero_digits_initial() {
+        let first = |sum, nonzero| {
+            WithNonZeroDigits {
+                sum: NonZeroU8::new(sum).unwrap(),
+                nonzero,
+            }
+            .get_ints()
+            .next()
+        };
+
+        assert_eq!(first(13, NonZeroDigits::AtMost(2)), Some(49));
+        assert_eq!(first(13, NonZeroDigits::Exactly(2)), Some(49));
+        assert_eq!(first(13, NonZeroDigits::Exactly(3)), Some(139));
+        assert_eq!(
+            first(13, NonZeroDigits::Exactly(13)),
+            Some(1_111_111_111_111)
+        );
+        assert_eq!(first(13, NonZeroDigits::Exactly(1)), None);
+        assert_eq!(first(13, NonZeroDigits::Exactly(14)), None);
+        assert_eq!(first(30, NonZeroDigits::AtMost(4)), Some(3999));
+
+        let all = WithNonZeroDigits {
+            sum: NonZeroU8::new(13).unwrap(),
+            nonzero: NonZeroDigits::Exactly(2),
+        };
+        assert_eq!(all.get_ints().count() as u64, all.count(u64::MAX));
+    }
+}
@@ -0,0 +1,175 @@
+use std::num::NonZeroU8;
+
+use crate::{
+    combinatorics::{Decimal, DigitRule, DigitWalk, count_below},
+    impl_mut_for_refmut, new_expect,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+const ALL_DIGITS: u16 = (1 << 10) - 1;
+
+/// Numbers with all the digits distinct which digits sum up to the given number.
+///
+/// There can't be more than 10 distinct digits, so the sequence is finite:
+/// it ends with the greatest number that has its digits in descending order.
+pub struct DistinctDigits(pub NonZeroU8);
+new_expect!(DistinctDigits);
+impl_mut_for_refmut!(DistinctDigits);
+
+impl SumSequencer for DistinctDigits {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        DigitWalk::new(Rule {
+            sum: self.0.get() as u32,
+            reachable: Reachable::new(),
+        })
+        .take(iterations as usize)
+    }
+}
+
+impl DistinctDigits {
+    /// Count all the numbers in range `0..end` which have distinct digits summing up to `self.0`.
+    pub fn count(&self, end: u64) -> u64 {
+        let sum = self.0.get() as u32;
+        let end = Decimal::new(end);
+        let end_length = end.len();
+
+        let shorter = (1..usize::min(end_length, 11))
+            .map(|length| {
+                (1..=9)
+                    .filter(|digit| *digit <= sum)
+                    .map(|digit| {
+                        count_placements(
+                            ALL_DIGITS & !(1 << digit),
+                            length - 1,
+                            sum - digit,
+                        )
+                    })
+                    .sum::<u64>()
+            })
+            .sum::<u64>();
+
+        if end_length > 10 {
+            return shorter;
+        }
+
+        let (same_length, _) = count_below(
+            end.digits(),
+            (ALL_DIGITS, sum),
+            |(available, left), position, digit| {
+                let first = if position == 0 { 1 } else { 0 };
+
+                if digit < first
+                    || digit > *left
+                    || available & (1 << digit) == 0
+                {
+                    return 0;
+                }
+
+                count_placements(
+                    available & !(1 << digit),
+                    end_length - position - 1,
+                    left - digit,
+                )
+            },
+            |(available, left), _, digit| {
+                let fits = available & (1 << digit) != 0 && digit <= left;
+
+                fits.then(|| (available & !(1 << digit), left - digit))
+            },
+        );
+
+        shorter + same_length
+    }
+
+    /// Count all the numbers with distinct digits summing up to `self.0`.
+    pub fn count_all(&self) -> u64 {
+        self.count(u64::MAX)
+    }
+}
+
+/// Number of ways to put `positions` distinct digits from `available` in a row, so that they sum up to `sum`.
+fn count_placements(available: u16, positions: usize, sum: u32) -> u64 {
+    let factorial = (1..=positions as u64).product::<u64>();
+
+    count_subsets(available, positions, sum) * factorial
+}
+
+fn count_subsets(available: u16, size: usize, sum: u32) -> u64 {
+    if size == 0 {
+        return (sum == 0) as u64;
+    }
+
+    if available == 0 {
+        return 0;
+    }
+
+    let digit = available.trailing_zeros();
+    let rest = available & (available - 1);
+
+    let without = count_subsets(rest, size, sum);
+
+    match sum.checked_sub(digit) {
+        Some(left) => without + count_subsets(rest, size - 1, left),
+        None => without,
+    }
+}
+
+/// `table[mask][size]` has bit `sum` set if `size` distinct digits from `mask` can sum up to `sum`.
+///
+/// The greatest possible sum is 45, so every set of sums fits into u64.
+struct Reachable(Vec<[u64; 11]>);
+
+impl Reachable {
+    fn new() -> Self {
+        let mut table = vec![[0u64; 11]; 1 << 10];
+        table[0][0] = 1;
+
+        for mask in 1..1usize << 10 {
+            let digit = mask.trailing_zeros();
+            let rest = mask & (mask - 1);
+
+            table[mask][0] = table[rest][0];
+
+            for size in 1..=10 {
+                table[mask][size] =
+                    table[rest][size] | (table[rest][size - 1] << digit);
+            }
+        }
+
+        Self(table)
+    }
+
+    fn contains(&self, available: u16, size: usize, sum: u32) -> bool {
+        size <= 10
+            && sum < 64
+            && (self.0[available as usize][size] >> sum) & 1 == 1
+    }
+}
+
+/// The state is the digits that are still available and the sum that is left.
+struct Rule {
+    sum: u32,
+    reachable: Reachable,
+}
+
+impl DigitRule for Rule {
+    type State = (u16, u32);
+
+    fn start(&self) -> Self::State {
+        (ALL_DIGITS, self.sum)
+    }
+
+    fn place(
+        &self,
+        (available, left): &Self::State,
+        digit: u32,
+        after: usize,
+    ) -> Option<Self::State> {
+        let rest = available & !(1 << digit);
+        let fits = available & (1 << digit) != 0
+            && digit <= *left
+            && self.reachable.contains(rest, after, left - digit);
+
+        fits.then(|| (rest, left - digit))
+    }
+}
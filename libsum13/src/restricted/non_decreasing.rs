@@ -0,0 +1,144 @@
+use std::num::NonZeroU8;
+
+use crate::{
+    combinatorics::{Decimal, DigitRule, DigitWalk, MAX_DIGITS, count_below},
+    impl_mut_for_refmut, new_expect,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+/// Numbers with non-decreasing digits which digits sum up to the given number.
+///
+/// The first digit is not zero, so there are no zeros at all.
+/// That makes every such number an integer partition of the sum into parts no greater than 9.
+/// The sequence is finite, but most of it doesn't fit into u64 for the greater sums.
+pub struct NonDecreasingDigits(pub NonZeroU8);
+new_expect!(NonDecreasingDigits);
+impl_mut_for_refmut!(NonDecreasingDigits);
+
+impl SumSequencer for NonDecreasingDigits {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        DigitWalk::new(Rule(self.0.get() as u32)).take(iterations as usize)
+    }
+}
+
+impl NonDecreasingDigits {
+    /// Count all the numbers in range `0..end` which have non-decreasing digits summing up to `self.0`.
+    pub fn count(&self, end: u64) -> u64 {
+        let sum = self.0.get() as u32;
+        let ways = Ways::new(sum);
+        let end = Decimal::new(end);
+        let end_length = end.len();
+
+        let shorter = (1..end_length)
+            .map(|length| ways.get(length, 1, sum))
+            .sum::<u64>();
+
+        let (same_length, _) = count_below(
+            end.digits(),
+            (1, sum),
+            |(previous, left), position, digit| {
+                if digit < *previous || digit > *left {
+                    return 0;
+                }
+
+                ways.get(end_length - position - 1, digit, left - digit)
+            },
+            |(previous, left), _, digit| {
+                (previous..=left)
+                    .contains(&digit)
+                    .then(|| (digit, left - digit))
+            },
+        );
+
+        shorter + same_length
+    }
+
+    /// Count all the numbers with non-decreasing digits summing up to `self.0`,
+    /// including the ones that don't fit into u64.
+    ///
+    /// That's the number of partitions of `self.0` into parts no greater than 9.
+    pub fn count_all(&self) -> u64 {
+        let sum = self.0.get() as usize;
+        let mut partitions = vec![0u64; sum + 1];
+        partitions[0] = 1;
+
+        for part in 1..=9 {
+            for total in part..=sum {
+                partitions[total] += partitions[total - part];
+            }
+        }
+
+        partitions[sum]
+    }
+}
+
+/// `get(length, lowest, sum)` is the number of non-decreasing digit sequences
+/// of the given length, starting from at least `lowest` and summing up to `sum`.
+struct Ways {
+    table: Vec<u64>,
+    sum: usize,
+}
+
+impl Ways {
+    fn new(sum: u32) -> Self {
+        let sum = sum as usize;
+        let index = |length: usize, lowest: usize, total: usize| {
+            (length * 11 + lowest) * (sum + 1) + total
+        };
+
+        let mut table = vec![0u64; (MAX_DIGITS + 1) * 11 * (sum + 1)];
+
+        for lowest in 0..=10 {
+            table[index(0, lowest, 0)] = 1;
+        }
+
+        for length in 1..=MAX_DIGITS {
+            for lowest in (0..=9).rev() {
+                for total in 0..=sum {
+                    let with_lowest =
+                        total.checked_sub(lowest).map_or(0, |left| {
+                            table[index(length - 1, lowest, left)]
+                        });
+
+                    table[index(length, lowest, total)] =
+                        with_lowest + table[index(length, lowest + 1, total)];
+                }
+            }
+        }
+
+        Self { table, sum }
+    }
+
+    fn get(&self, length: usize, lowest: u32, sum: u32) -> u64 {
+        self.table
+            [(length * 11 + lowest as usize) * (self.sum + 1) + sum as usize]
+    }
+}
+
+/// The state is the previous digit, which the next one can't be less than, and the sum that is left.
+///
+/// The first digit is at least 1, so there are no zeros at all.
+struct Rule(u32);
+
+impl DigitRule for Rule {
+    type State = (u32, u32);
+
+    fn start(&self) -> Self::State {
+        (1, self.0)
+    }
+
+    fn place(
+        &self,
+        (previous, left): &Self::State,
+        digit: u32,
+        after: usize,
+    ) -> Option<Self::State> {
+        let after = after as u32;
+        // The rest of the digits are at least `digit` and at most 9
+        let fits = digit >= *previous
+            && digit <= *left
+            && (after * digit..=after * 9).contains(&(left - digit));
+
+        fits.then(|| (digit, left - digit))
+    }
+}
@@ -0,0 +1,243 @@
+use std::num::NonZeroU8;
+
+use crate::{
+    combinatorics::{Decimal, count_below, digit_strings},
+    impl_mut_for_refmut, new_expect,
+    traits::{SumSequencer, SumSequencerMut},
+};
+
+/// Every 19-digit palindrome fits into u64, only the smallest 20-digit ones do.
+const MAX_LENGTH: usize = 20;
+const MAX_HALF: usize = MAX_LENGTH / 2;
+
+/// Palindromic numbers which digits sum up to the given number.
+///
+/// A palindrome is fully defined by its left half (middle digit included),
+/// so instead of filtering the whole sequence we only walk through the halves.
+/// Every digit of the half is counted twice, except for the middle one.
+/// That's why an even-length palindrome can never have an odd digits sum.
+pub struct Palindromes(pub NonZeroU8);
+new_expect!(Palindromes);
+impl_mut_for_refmut!(Palindromes);
+
+impl SumSequencer for Palindromes {
+    fn get_ints(&self, iterations: u32) -> impl Iterator<Item = u64> + use<> {
+        let sum = self.0.get() as u32;
+        let mut half = Half::first(sum);
+
+        std::iter::from_fn(move || {
+            let current = half.as_mut()?;
+            // The 20-digit palindromes grow with their halves, so past u64::MAX there are no more
+            let Some(value) = current.checked_value() else {
+                half = None;
+                return None;
+            };
+
+            if !current.advance(sum) {
+                half = None;
+            }
+
+            Some(value)
+        })
+        .take(iterations as usize)
+    }
+}
+
+impl Palindromes {
+    /// Count all the palindromes in range `0..end` which digits sum up to `self.0`.
+    pub fn count(&self, end: u64) -> u64 {
+        let sum = self.0.get() as u32;
+        let end_digits = Decimal::new(end);
+        let end_length = end_digits.len();
+
+        let shorter = (1..end_length)
+            .map(|length| {
+                (1..=9)
+                    .map(|digit| {
+                        let used = Half::weight_at(length, 0) * digit;
+
+                        sum.checked_sub(used)
+                            .map_or(0, |left| count_fillings(length, 1, left))
+                    })
+                    .sum::<u64>()
+            })
+            .sum::<u64>();
+
+        let mut half = Half {
+            digits: [0; MAX_HALF],
+            length: end_length,
+        };
+        let half_digits = &end_digits.digits()[..half.half_length()];
+
+        let (mut same_length, used) = count_below(
+            half_digits,
+            0,
+            |used, position, digit| {
+                let weight = Half::weight_at(end_length, position);
+                let first = if position == 0 { 1 } else { 0 };
+
+                if digit < first {
+                    return 0;
+                }
+
+                sum.checked_sub(used + weight * digit).map_or(0, |left| {
+                    count_fillings(end_length, position + 1, left)
+                })
+            },
+            |used, position, digit| {
+                Some(used + Half::weight_at(end_length, position) * digit)
+            },
+        );
+
+        for (digit, end_digit) in half.digits.iter_mut().zip(half_digits) {
+            *digit = *end_digit as u8;
+        }
+
+        if used == Some(sum)
+            && half.checked_value().is_some_and(|value| value < end)
+        {
+            same_length += 1;
+        }
+
+        shorter + same_length
+    }
+}
+
+/// Number of ways to fill the half positions `from..` so that they add exactly `left` to the digits sum.
+fn count_fillings(length: usize, from: usize, left: u32) -> u64 {
+    let half_length = length.div_ceil(2);
+
+    if from >= half_length {
+        return (left == 0) as u64;
+    }
+
+    let doubled = half_length - from;
+
+    if length.is_multiple_of(2) {
+        return if left.is_multiple_of(2) {
+            digit_strings(doubled, left / 2)
+        } else {
+            0
+        };
+    }
+
+    (0..=u32::min(9, left))
+        .filter(|middle| (left - middle).is_multiple_of(2))
+        .map(|middle| digit_strings(doubled - 1, (left - middle) / 2))
+        .sum()
+}
+
+/// The left half of a palindrome, most significant digit first.
+struct Half {
+    digits: [u8; MAX_HALF],
+    length: usize,
+}
+
+impl Half {
+    fn first(sum: u32) -> Option<Self> {
+        let mut half = Half {
+            digits: [0; MAX_HALF],
+            length: 0,
+        };
+
+        (1..=MAX_LENGTH).find(|length| half.reset(*length, sum))?;
+
+        Some(half)
+    }
+
+    fn half_length(&self) -> usize {
+        self.length.div_ceil(2)
+    }
+
+    /// The middle digit of an odd-length palindrome is the only one that is not mirrored.
+    fn weight_at(length: usize, position: usize) -> u32 {
+        if length % 2 == 1 && position == length / 2 {
+            1
+        } else {
+            2
+        }
+    }
+
+    fn checked_value(&self) -> Option<u64> {
+        let half = &self.digits[..self.half_length()];
+        let mirrored = &half[..self.length / 2];
+
+        half.iter()
+            .chain(mirrored.iter().rev())
+            .try_fold(0u64, |acc, digit| {
+                acc.checked_mul(10)?.checked_add(*digit as u64)
+            })
+    }
+
+    /// Make the half the smallest one of the given length. Returns false if there is none.
+    fn reset(&mut self, length: usize, sum: u32) -> bool {
+        self.length = length;
+
+        for digit in 1..=9 {
+            let used = Self::weight_at(length, 0) * digit;
+
+            if used > sum {
+                break;
+            }
+
+            self.digits[0] = digit as u8;
+
+            if self.fill_smallest(1, sum - used) {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Fill positions `from..` with the smallest digits that add up to `left`.
+    ///
+    /// The lower positions are greedily given as much as they can take,
+    /// which leaves the higher (more significant) positions as small as possible.
+    fn fill_smallest(&mut self, from: usize, mut left: u32) -> bool {
+        for position in (from..self.half_length()).rev() {
+            let digit = if Self::weight_at(self.length, position) == 1 {
+                let digit = u32::min(9, left);
+                // Everything that is left after the middle is mirrored, so it must be even
+                digit - (left - digit) % 2
+            } else {
+                u32::min(9, left / 2)
+            };
+
+            self.digits[position] = digit as u8;
+            left -= Self::weight_at(self.length, position) * digit;
+        }
+
+        left == 0
+    }
+
+    /// Move to the next palindrome in numeric order. Returns false once u64 is exhausted.
+    fn advance(&mut self, sum: u32) -> bool {
+        let mut used = (0..self.half_length())
+            .map(|position| {
+                Self::weight_at(self.length, position)
+                    * self.digits[position] as u32
+            })
+            .sum::<u32>();
+
+        for position in (0..self.half_length()).rev() {
+            let weight = Self::weight_at(self.length, position);
+            used -= weight * self.digits[position] as u32;
+
+            for digit in self.digits[position] as u32 + 1..=9 {
+                if used + weight * digit > sum {
+                    break;
+                }
+
+                self.digits[position] = digit as u8;
+
+                if self.fill_smallest(position + 1, sum - used - weight * digit)
+                {
+                    return true;
+                }
+            }
+        }
+
+        (self.length + 1..=MAX_LENGTH).any(|length| self.reset(length, sum))
+    }
+}
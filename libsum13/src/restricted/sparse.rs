@@ -0,0 +1,187 @@
+use std::{num::NonZeroU8, ops::RangeInclusive};
+
+use crate::combinatorics::{
+    Decimal, DigitRule, DigitWalk, MAX_DIGITS, count_below,
+};
+
+/// How many of the digits are allowed to be non-zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonZeroDigits {
+    Exactly(u8),
+    AtMost(u8),
+}
+
+impl NonZeroDigits {
+    fn range(self) -> RangeInclusive<u32> {
+        match self {
+            Self::Exactly(count) => count as u32..=count as u32,
+            Self::AtMost(count) => 0..=count as u32,
+        }
+    }
+}
+
+/// Numbers which digits sum up to `sum` and have only a few non-zero digits.
+///
+/// Unlike the plain digits sum, the state here is two-dimensional:
+/// the sum that is left and the number of non-zero digits that is left.
+/// That's why the first number is not `get_initial`'s greedy 9s:
+/// with exactly 3 non-zero digits the sum of 13 starts with 139, not with 49.
+#[derive(Debug)]
+pub struct WithNonZeroDigits {
+    pub sum: NonZeroU8,
+    pub nonzero: NonZeroDigits,
+}
+
+impl WithNonZeroDigits {
+    pub fn get_ints(&self) -> impl Iterator<Item = u64> + use<> {
+        DigitWalk::new(Rule {
+            sum: self.sum.get() as u32,
+            nonzero: self.nonzero.range(),
+        })
+    }
+
+    /// Count all the numbers in range `0..end` which fit the constraints.
+    pub fn count(&self, end: u64) -> u64 {
+        let sum = self.sum.get() as u32;
+        let nonzero = self.nonzero.range();
+        let ways = Ways::new();
+        let end = Decimal::new(end);
+        let end_length = end.len();
+
+        let shorter = (1..end_length)
+            .flat_map(|length| (1..=9).map(move |first| (length, first)))
+            .filter(|(_, first)| *first <= sum)
+            .map(|(length, first)| {
+                place(&nonzero, first)
+                    .map_or(0, |rest| ways.get(length - 1, sum - first, rest))
+            })
+            .sum::<u64>();
+
+        let (same_length, _) = count_below(
+            end.digits(),
+            (sum, nonzero),
+            |(left, nonzero), position, digit| {
+                let first = if position == 0 { 1 } else { 0 };
+
+                if digit < first || digit > *left {
+                    return 0;
+                }
+
+                place(nonzero, digit).map_or(0, |rest| {
+                    ways.get(end_length - position - 1, left - digit, rest)
+                })
+            },
+            |(left, nonzero), _, digit| {
+                let rest = place(&nonzero, digit).filter(|_| digit <= left)?;
+
+                Some((left - digit, rest))
+            },
+        );
+
+        shorter + same_length
+    }
+}
+
+/// The non-zero digits that are left after placing `digit`, if it can be placed at all.
+fn place(
+    nonzero: &RangeInclusive<u32>,
+    digit: u32,
+) -> Option<RangeInclusive<u32>> {
+    if digit == 0 {
+        Some(nonzero.clone())
+    } else {
+        Some(nonzero.start().saturating_sub(1)..=nonzero.end().checked_sub(1)?)
+    }
+}
+
+/// `positions` digits can sum up to `left` with the number of non-zero ones in `nonzero`.
+fn fits(positions: usize, left: u32, nonzero: &RangeInclusive<u32>) -> bool {
+    let lowest = u32::max(*nonzero.start(), left.div_ceil(9));
+    let highest = u32::min(*nonzero.end(), u32::min(positions as u32, left));
+
+    lowest <= highest
+}
+
+/// Counts the digit strings by the number of their non-zero digits.
+///
+/// Out of `positions` digits, `nonzero` ones can be chosen in C(positions, nonzero) ways,
+/// and those have `nonzero_strings[nonzero][sum]` ways to sum up to `sum`.
+struct Ways {
+    binomials: [[u64; MAX_DIGITS + 1]; MAX_DIGITS + 1],
+    nonzero_strings: [[u64; 9 * MAX_DIGITS + 1]; MAX_DIGITS + 1],
+}
+
+impl Ways {
+    fn new() -> Self {
+        let mut binomials = [[0u64; MAX_DIGITS + 1]; MAX_DIGITS + 1];
+        let mut nonzero_strings = [[0u64; 9 * MAX_DIGITS + 1]; MAX_DIGITS + 1];
+        binomials[0][0] = 1;
+        nonzero_strings[0][0] = 1;
+
+        for positions in 1..=MAX_DIGITS {
+            binomials[positions][0] = 1;
+
+            for chosen in 1..=positions {
+                binomials[positions][chosen] = binomials[positions - 1]
+                    [chosen - 1]
+                    + binomials[positions - 1][chosen];
+            }
+
+            for sum in positions..=9 * positions {
+                nonzero_strings[positions][sum] = (1..=usize::min(9, sum))
+                    .map(|digit| nonzero_strings[positions - 1][sum - digit])
+                    .sum();
+            }
+        }
+
+        Self {
+            binomials,
+            nonzero_strings,
+        }
+    }
+
+    fn get(
+        &self,
+        positions: usize,
+        sum: u32,
+        nonzero: RangeInclusive<u32>,
+    ) -> u64 {
+        if sum as usize > 9 * positions {
+            return 0;
+        }
+
+        nonzero
+            .map(|count| count as usize)
+            .filter(|count| *count <= positions)
+            .map(|count| {
+                self.binomials[positions][count]
+                    * self.nonzero_strings[count][sum as usize]
+            })
+            .sum()
+    }
+}
+
+/// The state is the sum that is left and the non-zero digits that are left.
+struct Rule {
+    sum: u32,
+    nonzero: RangeInclusive<u32>,
+}
+
+impl DigitRule for Rule {
+    type State = (u32, RangeInclusive<u32>);
+
+    fn start(&self) -> Self::State {
+        (self.sum, self.nonzero.clone())
+    }
+
+    fn place(
+        &self,
+        (left, nonzero): &Self::State,
+        digit: u32,
+        after: usize,
+    ) -> Option<Self::State> {
+        let rest = place(nonzero, digit).filter(|_| digit <= *left)?;
+
+        fits(after, left - digit, &rest).then_some((left - digit, rest))
+    }
+}
@@ -0,0 +1,195 @@
+//! Here are all the samplers of random numbers with the given digits sum
+
+mod random;
+
+use std::{collections::HashMap, num::NonZeroU8};
+
+pub use random::SplitMix64;
+
+use crate::combinatorics::{count_digit_sum, nth_digit_sum};
+
+/// Uniformly random numbers in range `0..end` which digits sum up to `sum`.
+///
+/// Every draw picks a random rank below the count and unranks it digit by digit,
+/// so nothing is ever drawn and thrown away.
+#[derive(Debug, Clone)]
+pub struct DigitSumSampler {
+    sum: u8,
+    end: u64,
+    count: u64,
+    rng: SplitMix64,
+}
+
+impl DigitSumSampler {
+    pub fn new(sum: NonZeroU8, end: u64, seed: u64) -> Self {
+        let sum = sum.get();
+
+        Self {
+            sum,
+            end,
+            count: count_digit_sum(sum, end),
+            rng: SplitMix64::new(seed),
+        }
+    }
+
+    /// How many numbers there are to draw from.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The `n`-th number in increasing order, if there is one.
+    pub fn nth(&self, n: u64) -> Option<u64> {
+        nth_digit_sum(self.sum, self.end, n)
+    }
+
+    /// One random number, or `None` if there are none at all.
+    pub fn sample(&mut self) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let rank = self.rng.below(self.count);
+        self.nth(rank)
+    }
+
+    /// Random numbers with replacement, infinitely.
+    pub fn samples(&mut self) -> impl Iterator<Item = u64> + '_ {
+        std::iter::from_fn(|| self.sample())
+    }
+
+    /// Random numbers without replacement: all of them in a random order.
+    ///
+    /// It's the Fisher-Yates shuffle of the ranks, where only the swapped ranks are stored.
+    pub fn distinct(&mut self) -> impl Iterator<Item = u64> + '_ {
+        let mut swapped = HashMap::<u64, u64>::new();
+
+        (0..self.count).map(move |drawn| {
+            let picked = drawn + self.rng.below(self.count - drawn);
+            let rank = swapped.get(&picked).copied().unwrap_or(picked);
+            let replaced = swapped.remove(&drawn).unwrap_or(drawn);
+
+            if picked != drawn {
+                swapped.insert(picked, replaced);
+            }
+
+            self.nth(rank).expect("The rank is below the count")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, num::NonZeroU8};
+
+    use crate::{integer, traits::SumSequencer};
+
+    use super::{DigitSumSampler, SplitMix64};
+
+    #[test]
+    fn test_split_mix() {
+        let mut rng = SplitMix64::new(1234567);
+        let mut same = SplitMix64::new(1234567);
+
+        for _ in 0..1000 {
+            assert_eq!(rng.next_u64(), same.next_u64());
+        }
+
+        for bound in [1, 2, 3, 10, 1000, u64::MAX] {
+            assert!((0..1000).all(|_| rng.below(bound) < bound));
+        }
+    }
+
+    #[test]
+    fn test_nth_against_enumeration() {
+        for sum in (1..=40).filter_map(NonZeroU8::new) {
+            let all = integer::WithDigitSum(sum)
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < 1_000_000)
+                .collect::<Vec<_>>();
+
+            for end in [0, 1, 99, 12_345, 100_000, 999_999] {
+                let expected = all
+                    .iter()
+                    .copied()
+                    .filter(|value| *value < end)
+                    .collect::<Vec<_>>();
+                let sampler = DigitSumSampler::new(sum, end, 0);
+
+                assert_eq!(sampler.count(), expected.len() as u64);
+                assert_eq!(
+                    (0..sampler.count())
+                        .map(|n| sampler.nth(n).unwrap())
+                        .collect::<Vec<_>>(),
+                    expected,
+                    "sum {sum}, end {end}"
+                );
+                assert_eq!(sampler.nth(sampler.count()), None);
+            }
+        }
+
+        let sampler =
+            DigitSumSampler::new(NonZeroU8::new(1).unwrap(), u64::MAX, 0);
+        assert_eq!(sampler.count(), 20);
+        assert_eq!(sampler.nth(19), Some(10_000_000_000_000_000_000));
+    }
+
+    #[test]
+    fn test_uniformity() {
+        let sum = NonZeroU8::new(13).unwrap();
+        let end = 1000;
+        let expected = integer::WithDigitSum(sum)
+            .get_ints(u32::MAX)
+            .take_while(|value| *value < end)
+            .collect::<Vec<_>>();
+
+        let mut sampler = DigitSumSampler::new(sum, end, 42);
+        let per_value = 200;
+        let draws = expected.len() * per_value;
+        let mut frequencies = HashMap::new();
+
+        for value in sampler.samples().take(draws) {
+            *frequencies.entry(value).or_insert(0u64) += 1;
+        }
+
+        let mut keys = frequencies.keys().copied().collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, expected);
+
+        let chi_square = frequencies
+            .values()
+            .map(|observed| {
+                let difference = *observed as f64 - per_value as f64;
+                difference * difference / per_value as f64
+            })
+            .sum::<f64>();
+
+        // 74 degrees of freedom: the critical value for p = 0.001 is about 116
+        assert_eq!(expected.len(), 75);
+        assert!(chi_square < 116.0, "chi-square {chi_square}");
+    }
+
+    #[test]
+    fn test_distinct() {
+        let sum = NonZeroU8::new(13).unwrap();
+        let end = 50_000;
+        let mut sampler = DigitSumSampler::new(sum, end, 7);
+
+        let drawn = sampler.distinct().collect::<Vec<_>>();
+        let mut sorted = drawn.clone();
+        sorted.sort();
+
+        assert_eq!(
+            sorted,
+            integer::WithDigitSum(sum)
+                .get_ints(u32::MAX)
+                .take_while(|value| *value < end)
+                .collect::<Vec<_>>()
+        );
+        assert_ne!(drawn, sorted);
+
+        let again = DigitSumSampler::new(sum, end, 7)
+            .distinct()
+            .collect::<Vec<_>>();
+        assert_eq!(drawn, again);
+    }
+}
@@ -0,0 +1,38 @@
+/// SplitMix64: a tiny seedable generator, good enough for the tests and the load generation.
+///
+/// The same seed always gives the same numbers, so the runs are reproducible.
+#[derive(Debug, Clone)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut value = self.0;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        value ^ (value >> 31)
+    }
+
+    /// Uniformly random number in range `0..bound`.
+    ///
+    /// Lemire's multiply-shift: the low part of the product tells which draws would make it biased.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound != 0, "Bound must be nonzero");
+
+        let threshold = bound.wrapping_neg() % bound;
+
+        loop {
+            let product = self.next_u64() as u128 * bound as u128;
+
+            if product as u64 >= threshold {
+                return (product >> 64) as u64;
+            }
+        }
+    }
+}
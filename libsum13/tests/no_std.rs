@@ -0,0 +1,23 @@
+//! The core sequencers must build with `core` only, so the crate is checked without its features.
+
+use std::process::Command;
+
+#[test]
+fn test_no_std_build() {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+
+    // A target directory of its own, so the outer cargo's lock is never waited for
+    let output = Command::new(env!("CARGO"))
+        .args(["check", "--lib", "--no-default-features", "--target-dir"])
+        .arg(format!("{manifest_dir}/../target/no_std"))
+        .arg("--manifest-path")
+        .arg(format!("{manifest_dir}/Cargo.toml"))
+        .output()
+        .expect("Cargo must be runnable");
+
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -55,6 +55,12 @@ fn main() {
 
     measure_fun(integer::WithDigitSum13 {}, iterations, "integer_static");
 
+    measure_fun(
+        integer::WithDigitSumConst::<13>,
+        iterations,
+        "integer_const",
+    );
+
     measure_fun(integer::WithDigitSum(sum), iterations, "integer_dynamic");
 
     measure_fun(